@@ -0,0 +1,80 @@
+use criterion::{ criterion_group, criterion_main, BenchmarkId, Criterion };
+use highest_number_pairings::{ Goal, NumberPairing, NumberPairingProblem, ProductTimesDifference };
+use std::hint::black_box;
+use std::sync::Arc;
+use std::sync::atomic::{ AtomicUsize, Ordering };
+
+/// The sums each solver is benchmarked across
+const SUMS: [f64; 4] = [1.0, 8.0, 1_000.0, 1_000_000.0];
+
+/// The sums the recursive search and golden-section search are compared at
+const COMPARED_SUMS: [f64; 2] = [8.0, 1_000.0];
+
+/// Counts the pairings the recursive search evaluates for the given sum
+fn recursive_evaluations(sum: f64) -> usize {
+    let evaluations = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&evaluations);
+    NumberPairingProblem::builder()
+        .collect_other_results(false)
+        .objective(move |pairing: &NumberPairing| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            pairing.result()
+        })
+        .solve(sum);
+    evaluations.load(Ordering::Relaxed)
+}
+
+/// Benchmarks each solver across the sums, naming each benchmark with how many runs (or evaluations) it took
+fn bench_solvers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("solve");
+    for sum in SUMS {
+        for collect_other_results in [true, false] {
+            let solve = |sum: f64| NumberPairingProblem::solve_with(sum, collect_other_results, false, ProductTimesDifference, Goal::Maximize);
+            let name = if collect_other_results { "solve_with (collecting others)" } else { "solve_with" };
+            let id = BenchmarkId::new(format!("{}, {} runs", name, solve(sum).runs_to_solve()), sum);
+            group.bench_with_input(id, &sum, |b, sum| b.iter(|| solve(black_box(*sum))));
+        }
+        let unseeded_runs = NumberPairingProblem::builder().seeded(false).solve(sum).runs_to_solve();
+        group.bench_with_input(BenchmarkId::new(format!("unseeded, {} runs", unseeded_runs), sum), &sum, |b, sum| {
+            b.iter(|| NumberPairingProblem::builder().seeded(false).solve(black_box(*sum)))
+        });
+        let golden_evaluations = NumberPairingProblem::solve_golden_section(sum, false).runs_to_solve();
+        group.bench_with_input(BenchmarkId::new(format!("golden section, {} evaluations", golden_evaluations), sum), &sum, |b, sum| {
+            b.iter(|| NumberPairingProblem::solve_golden_section(black_box(*sum), false))
+        });
+        group.bench_with_input(BenchmarkId::new("analytic", sum), &sum, |b, sum| {
+            b.iter(|| NumberPairingProblem::solve_analytic(black_box(*sum)))
+        });
+    }
+    group.finish();
+}
+
+/// Compares how many pairings the recursive search and golden-section search evaluate,
+/// naming each benchmark with its evaluation count
+fn bench_evaluations(c: &mut Criterion) {
+    let mut group = c.benchmark_group("evaluations");
+    for sum in COMPARED_SUMS {
+        let recursive = recursive_evaluations(sum);
+        let golden = NumberPairingProblem::solve_golden_section(sum, false).runs_to_solve();
+        println!("sum {}: recursive search evaluated {} pairings, golden section evaluated {}", sum, recursive, golden);
+        group.bench_with_input(BenchmarkId::new(format!("recursive, {} evaluations", recursive), sum), &sum, |b, sum| {
+            b.iter(|| NumberPairingProblem::builder().collect_other_results(false).solve(black_box(*sum)))
+        });
+        group.bench_with_input(BenchmarkId::new(format!("golden section, {} evaluations", golden), sum), &sum, |b, sum| {
+            b.iter(|| NumberPairingProblem::solve_golden_section(black_box(*sum), false))
+        });
+    }
+    group.finish();
+}
+
+/// Benchmarks solving many sums in a row while collecting the other results,
+/// where each run shuffles pairings between the best and other results
+fn bench_collecting(c: &mut Criterion) {
+    let sums: Vec<f64> = (1..=100).map(f64::from).collect();
+    c.bench_function("solve_batch (collecting others), 100 sums", |b| {
+        b.iter(|| NumberPairingProblem::solve_batch(black_box(&sums), true))
+    });
+}
+
+criterion_group!(benches, bench_solvers, bench_evaluations, bench_collecting);
+criterion_main!(benches);
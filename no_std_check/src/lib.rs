@@ -0,0 +1,22 @@
+#![no_std]
+
+use core::cmp::Ordering;
+use highest_number_pairings::{ NumberPairing, PairingError };
+
+/// The result of the pairing of the given number, or `None` if the input is invalid
+pub fn result_of(number: f64, sum: f64) -> Option<f64> {
+    NumberPairing::try_new(number, sum).ok().map(|pairing| pairing.result())
+}
+
+/// Compares the pairings of two numbers with the same sum
+pub fn compare(first: f64, second: f64, sum: f64) -> Result<Ordering, PairingError> {
+    let first = NumberPairing::try_new(first, sum)?;
+    let second = NumberPairing::try_new(second, sum)?;
+    Ok(first.cmp(&second))
+}
+
+/// The product and difference of the pairing of the given number
+pub fn parts_of(number: f64, sum: f64) -> (f64, f64) {
+    let pairing = NumberPairing::new(number, sum);
+    (pairing.product(), pairing.difference())
+}
@@ -1,2 +1,16 @@
+#[cfg(feature = "decimal")]
+pub mod decimal_pairing;
+#[cfg(feature = "std")]
+pub mod dto;
+#[cfg(feature = "std")]
+pub mod fixed_product_problem;
 pub mod number_pairing;
-pub mod number_pairing_problem;
\ No newline at end of file
+#[cfg(feature = "std")]
+pub mod number_pairing_problem;
+pub mod objective;
+#[cfg(feature = "std")]
+pub mod partition_problem;
+#[cfg(feature = "std")]
+pub mod problem_view;
+#[cfg(feature = "std")]
+pub mod solve_report;
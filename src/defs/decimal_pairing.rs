@@ -0,0 +1,62 @@
+use super::number_pairing::NumberPairing;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::FromPrimitive;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::result::Result;
+use std::fmt::Error;
+
+/// A NumberPairing backed by exact decimal arithmetic instead of f64.
+/// This avoids float rounding artifacts, e.g. for presenting the integer solution.
+#[derive(Debug, Clone, Copy)]
+pub struct DecimalPairing {
+    one_number: Decimal,
+    pub sum: Decimal,
+}
+
+impl DecimalPairing {
+    pub fn first(&self) -> Decimal { self.one_number }
+    pub fn second(&self) -> Decimal { self.sum - self.one_number }
+    pub fn difference(&self) -> Decimal { (self.one_number - self.second()).abs() }
+
+    /// The product, or `None` if it's too large for a `Decimal`
+    pub fn product(&self) -> Option<Decimal> { self.one_number.checked_mul(self.second()) }
+
+    /// The result (the product multiplied by the difference), or `None` if it's too large for a `Decimal`
+    pub fn result(&self) -> Option<Decimal> { self.product()?.checked_mul(self.difference()) }
+
+    // Initializers ---------------------------------------------------------- /
+
+    /// Creates a pairing, clamping the requested number to be non-negative and not more than the sum.
+    pub fn new(requested_number: Decimal, sum: Decimal) -> Self {
+        let non_negative = requested_number.abs();
+        let one_number = if non_negative > sum { sum } else { non_negative };
+        Self { one_number, sum }
+    }
+
+    /// Converts a NumberPairing, if its numbers can be represented as decimals
+    pub fn from_pairing(pairing: &NumberPairing) -> Option<Self> {
+        let first = Decimal::from_f64(pairing.first())?;
+        let sum = Decimal::from_f64(pairing.sum)?;
+        Some(Self::new(first, sum))
+    }
+}
+
+impl Display for DecimalPairing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let precision = 4;
+        let format = |decimal: Decimal| decimal.round_dp(precision).normalize();
+        // Values too large for a `Decimal` are shown as overflowed rather than panicking
+        let format_checked = |decimal: Option<Decimal>| decimal.map_or(String::from("overflow"), |decimal| format(decimal).to_string());
+        write!(
+            f,
+            "{} and {} -> {} (difference: {}, product: {} -> result: {})",
+            format(self.first()),
+            format(self.second()),
+            format(self.sum),
+            format(self.difference()),
+            format_checked(self.product()),
+            format_checked(self.result())
+        )
+    }
+}
@@ -0,0 +1,59 @@
+use super::number_pairing::NumberPairing;
+use super::number_pairing_problem::{ NumberPairingProblem, DEFAULT_DISPLAY_LIMIT };
+use serde::Serialize;
+
+/// A serializable snapshot of a NumberPairing, including its derived values
+#[derive(Serialize)]
+pub struct PairingDto {
+    pub first: f64,
+    pub second: f64,
+    pub sum: f64,
+    pub product: f64,
+    pub difference: f64,
+    pub result: f64,
+}
+
+/// A serializable snapshot of a solved NumberPairingProblem
+/// `other` is null when other results weren't collected.
+#[derive(Serialize)]
+pub struct ProblemDto {
+    pub sum: f64,
+    pub runs_to_solve: u32,
+    pub best: Option<f64>,
+    pub best_pairing: Vec<PairingDto>,
+    pub other: Option<Vec<PairingDto>>,
+}
+
+impl From<&NumberPairing> for PairingDto {
+    fn from(pairing: &NumberPairing) -> Self {
+        Self {
+            first: pairing.first(),
+            second: pairing.second(),
+            sum: pairing.sum,
+            product: pairing.product(),
+            difference: pairing.difference(),
+            result: pairing.result(),
+        }
+    }
+}
+
+impl From<&NumberPairingProblem> for ProblemDto {
+    fn from(problem: &NumberPairingProblem) -> Self {
+        let NumberPairingProblem { sum, results, .. } = problem;
+        let mut dto = Self {
+            sum: *sum,
+            runs_to_solve: problem.runs_to_solve(),
+            best: None,
+            best_pairing: Vec::new(),
+            other: None,
+        };
+        if let Some(results) = results {
+            dto.best = Some(results.best());
+            dto.best_pairing = results.best_pairing().iter().map(PairingDto::from).collect();
+            dto.other = results.other_results().map(|other_pairings| {
+                other_pairings.iter().take(DEFAULT_DISPLAY_LIMIT).map(PairingDto::from).collect()
+            });
+        }
+        dto
+    }
+}
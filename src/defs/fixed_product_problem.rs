@@ -0,0 +1,52 @@
+use super::number_pairing::NumberPairing;
+
+/// # FixedProductProblem
+/// The counterpart of NumberPairingProblem with the product fixed instead of the sum:
+/// two non-negative numbers that multiply to a given amount (a negative product is made positive).
+///
+/// Unlike a fixed sum, a fixed product doesn't bound the numbers (one can grow without limit as the
+/// other shrinks), so the difference, and the product multiplied by the difference, have no maximum.
+/// Instead, this solves for the smallest sum (and difference), or for a given difference or result.
+pub struct FixedProductProblem {
+    pub product: f64,
+}
+
+impl FixedProductProblem {
+
+    // Initializers ---------------------------------------------------------- /
+
+    pub fn new(product: f64) -> Self {
+        Self { product }
+    }
+
+    // Methods --------------------------------------------------------------- /
+
+    /// Finds the pairing with the smallest sum, which is where both numbers are the square root of the
+    /// product (so the difference, and the result, are zero)
+    pub fn solve(&self) -> NumberPairing {
+        let root = self.product.abs().sqrt();
+        NumberPairing::new(root, root * 2.0)
+    }
+
+    /// Finds the pairing whose numbers are the given difference apart.
+    /// Solving `a − b = d` and `a·b = P` for the smaller number gives `b = (√(d² + 4P) − d) / 2`.
+    pub fn pairing_with_difference(&self, difference: f64) -> NumberPairing {
+        let difference = difference.abs();
+        let smaller = ((difference * difference + 4.0 * self.product.abs()).sqrt() - difference) / 2.0;
+        NumberPairing::new(smaller, smaller * 2.0 + difference)
+    }
+
+    /// Finds the pairing whose result (the product multiplied by the difference) is the given value.
+    /// With the product fixed, the result is proportional to the difference, so every result has
+    /// exactly one pairing (none if the product is zero, unless the result is zero too).
+    pub fn pairing_with_result(&self, result: f64) -> Option<NumberPairing> {
+        let product = self.product.abs();
+        if product > 0.0 {
+            Some(self.pairing_with_difference(result / product))
+        } else if result == 0.0 {
+            Some(self.solve())
+        } else {
+            None
+        }
+    }
+}
@@ -1,66 +1,339 @@
-use std::fmt::Display;
-use std::fmt::Formatter;
-use std::result::Result;
-use std::fmt::Error;
-use std::cmp::{ Eq, PartialEq };
-use std::cmp::{ Ord, PartialOrd, Ordering };
-use std::hash::{ Hash, Hasher };
-use std::marker::Copy;
-use std::clone::Clone;
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::result::Result;
+use core::fmt::Error;
+use core::cmp::{ Eq, PartialEq };
+use core::cmp::{ Ord, PartialOrd, Ordering };
+use core::hash::{ Hash, Hasher };
+use core::marker::Copy;
+use core::clone::Clone;
+use core::convert::TryFrom;
+use crate::math;
+#[cfg(feature = "num-rational")]
+use num_rational::Ratio;
+#[cfg(feature = "num-rational")]
+use num_traits::{ CheckedMul, CheckedSub };
+#[cfg(feature = "std")]
 use crate::util::format_float;
+use super::objective::Objective;
+
+/// The minimum level of precision we care about... beyond this point, we'll consider values equal
+pub const MINIMUM_PRECISION: f64 = 0.000_000_000_1;
 
 /// A structure that stores two numbers that sum to a given amount.
 /// Finds the product, the difference and the result of multiplying the difference and the product.
 pub struct NumberPairing {
     one_number: f64,
+    /// The result is cached when the pairing is created, so changing the sum directly won't update it
     pub sum: f64,
+    // The result is compared constantly during a search, so it's computed once up front
+    cached_result: f64,
 }
 
 impl NumberPairing {
+    /// The optimal deviation from the center, as a ratio of the sum: 1 / (2√3)
+    pub const OPTIMAL_DEVIATION_RATIO: f64 = 0.288_675_134_594_812_9;
+
     pub fn first(&self) -> f64 { self.one_number }
     pub fn set_first(&mut self, requested_number: f64) {
         self.one_number = self.validate_and_correct(requested_number);
+        self.cached_result = self.compute_result();
     }
 
     pub fn second(&self) -> f64 { self.sum - self.one_number }
     pub fn set_second(&mut self, requested_number: f64) {
         self.one_number = self.sum - self.validate_and_correct(requested_number);
+        self.cached_result = self.compute_result();
     }
 
     pub fn product(&self) -> f64 { self.one_number * self.second() }
     pub fn difference(&self) -> f64 { (self.one_number - self.second()).abs() }
-    pub fn result(&self) -> f64 { self.product() * self.difference() }
+    pub fn result(&self) -> f64 { self.cached_result }
+
+    /// The product and the difference added together with the given weights, rather than multiplied
+    /// (as in `result`), which trades them off differently
+    pub fn weighted_result(&self, product_weight: f64, difference_weight: f64) -> f64 {
+        product_weight * self.product() + difference_weight * self.difference()
+    }
+
+    #[cfg(feature = "num-rational")]
+    /// The result as an exact fraction, which is exact for pairings of whole numbers or simple fractions
+    /// (other numbers are approximated by the nearest fraction that fits).
+    /// Returns `None` if the numbers can't be written as fractions of `i64`s, or the result overflows.
+    pub fn result_rational(&self) -> Option<Ratio<i64>> {
+        let first = Ratio::<i64>::approximate_float(self.first())?;
+        let sum = Ratio::<i64>::approximate_float(self.sum)?;
+        let second = sum.checked_sub(&first)?;
+        let difference = if first > second { first.checked_sub(&second)? } else { second.checked_sub(&first)? };
+        first.checked_mul(&second)?.checked_mul(&difference)
+    }
+
+    /// Both numbers, smaller first, so a pairing and its mirror give the same tuple
+    pub fn as_sorted(&self) -> (f64, f64) {
+        let (first, second) = (self.first(), self.second());
+        (first.min(second), first.max(second))
+    }
+
+    /// How close the result is to the best possible for the sum, from 0 (at either end) to 1 (optimal).
+    /// A sum of zero can only have a result of zero, which is optimal.
+    pub fn optimality_ratio(&self) -> f64 {
+        let optimal = Self::optimal_for(self.sum).result();
+        if optimal > 0.0 { self.result() / optimal } else { 1.0 }
+    }
+
+    /// Whether the result is within the given fraction of optimal (e.g. 0.01 for within 1%)
+    pub fn is_near_optimal(&self, within: f64) -> bool { self.optimality_ratio() >= 1.0 - within }
+
+    /// The midpoint of the sum, where both numbers would be equal
+    pub fn center(&self) -> f64 { self.sum / 2.0 }
+    /// How far the numbers are from the center (half their difference)
+    pub fn deviation(&self) -> f64 { (self.first() - self.center()).abs() }
+
+    /// The result, or `None` if the product or the result overflowed (e.g. for a huge sum)
+    pub fn checked_result(&self) -> Option<f64> {
+        if self.product().is_finite() && self.result().is_finite() { Some(self.result()) } else { None }
+    }
+
+    /// The result scaled by the sum cubed, so pairings with different sums can be compared
+    /// At the optimum, this is always 1 / (6√3) ≈ 0.0962, whatever the sum
+    pub fn normalized_result(&self) -> f64 { self.result() / math::powi(self.sum, 3) }
 
     // Initializers ---------------------------------------------------------- /
 
+    /// Creates a pairing, clamping the requested number to be non-negative and not more than the sum.
+    /// Use `try_new` to have invalid input reported instead of corrected.
     pub fn new(requested_number: f64, sum: f64) -> Self {
-        Self { one_number: Self::validate_and_correct_input(requested_number, &sum), sum }
+        Self::from_parts(Self::validate_and_correct_input(requested_number, &sum), sum)
     }
 
     pub fn default(requested_number: f64) -> Self {
         let sum = Self::default_sum();
-        Self { one_number: Self::validate_and_correct_input(requested_number, &sum), sum }
+        Self::from_parts(Self::validate_and_correct_input(requested_number, &sum), sum)
+    }
+
+    /// Creates a pairing whose numbers are the given deviation either side of the center of the sum.
+    /// The deviation is clamped, the same as `new`, so it can't be more than half the sum.
+    pub fn from_deviation(sum: f64, deviation: f64) -> Self {
+        Self::new(sum / 2.0 - deviation.abs().min(sum / 2.0), sum)
+    }
+
+    /// The optimal pairing for the given sum (with the highest result), found exactly without searching
+    pub fn optimal_for(sum: f64) -> Self {
+        Self::from_deviation(sum, sum * Self::OPTIMAL_DEVIATION_RATIO)
+    }
+
+    /// The pairing with the highest product alone, which is both numbers at the center of the sum.
+    /// This is also where the difference (and so the result) is zero; the result is highest off-center,
+    /// trading some of the product for a difference (see `optimal_for`).
+    pub fn max_product_pairing(sum: f64) -> Self {
+        Self::from_deviation(sum, 0.0)
+    }
+
+    /// The highest product any pairing of the sum has, `S²/4`
+    pub fn max_product(sum: f64) -> f64 {
+        sum * sum / 4.0
+    }
+
+    /// How quickly the optimal result grows with the sum, at the given sum.
+    /// The optimal result is `k·S³` (where `k = 1 / (6√3)`), so this is `3k·S²`.
+    pub fn d_result_d_sum_at_optimum(sum: f64) -> f64 {
+        3.0 * Self::optimal_for(1.0).result() * sum * sum
+    }
+
+    /// Finds the pairing of the given sum that produces the given result, if there is one.
+    /// Most results can be made by two pairings, either side of the optimum, so this returns the one
+    /// with the numbers closer together (found by bisecting the deviation, where the result is increasing).
+    /// Returns `None` if the result is negative or more than the sum can produce.
+    pub fn from_result(result: f64, sum: f64) -> Option<Self> {
+        if !Self::is_achievable(result, sum) {
+            return None;
+        }
+        let deviation = Self::deviation_for_result(result, sum, 0.0, sum * Self::OPTIMAL_DEVIATION_RATIO, true);
+        Some(Self::from_deviation(sum, deviation))
+    }
+
+    #[cfg(feature = "std")]
+    /// Finds every pairing of the given sum that produces the given result, each followed by its mirror
+    /// (the same numbers in the other order). A result below the optimum is produced at two deviations
+    /// from the center, either side of the optimum, so there are usually four pairings. The optimum itself
+    /// has just the one deviation (so two pairings), while a result of zero has the equal split (a single
+    /// pairing) as well as zero and the sum. Returns nothing if the result is negative or more than the sum can produce.
+    pub fn solutions_for_result(result: f64, sum: f64) -> Vec<Self> {
+        if !Self::is_achievable(result, sum) {
+            return Vec::new();
+        }
+        let optimal_deviation = sum * Self::OPTIMAL_DEVIATION_RATIO;
+        let deviations = if result >= Self::optimal_for(sum).result() - Self::minimum_precision() {
+            vec![optimal_deviation]
+        } else {
+            vec![
+                Self::deviation_for_result(result, sum, 0.0, optimal_deviation, true),
+                Self::deviation_for_result(result, sum, optimal_deviation, sum / 2.0, false),
+            ]
+        };
+        let mut solutions = Vec::new();
+        for deviation in deviations {
+            solutions.push(Self::from_deviation(sum, deviation));
+            // Unless the numbers are equal (so the mirror is the same pairing)
+            if deviation * 2.0 > Self::minimum_precision() {
+                solutions.push(Self::new(sum / 2.0 + deviation, sum));
+            }
+        }
+        solutions
+    }
+
+    /// Creates a pairing, returning an error (rather than clamping) if the input is invalid.
+    pub fn try_new(requested_number: f64, sum: f64) -> Result<Self, PairingError> {
+        if !requested_number.is_finite() || !sum.is_finite() {
+            Err(PairingError::NonFinite)
+        } else if sum < 0.0 {
+            Err(PairingError::NegativeSum)
+        } else if requested_number < 0.0 {
+            Err(PairingError::NumberNegative)
+        } else if requested_number > sum {
+            Err(PairingError::NumberExceedsSum)
+        } else {
+            Ok(Self::from_parts(requested_number, sum))
+        }
     }
 
     // Methods --------------------------------------------------------------- /
 
+    /// Checks that the numbers add up to the sum, within a tolerance of `minimum_precision * sum.abs().max(1.0)`.
+    /// Adding the numbers back up can be off by a rounding error in the last place of the sum (about `sum * 2.2e-16`),
+    /// which is more than the minimum precision itself for huge sums, so the tolerance is relative to sums over 1.
+    /// This holds by construction (every constructor debug-asserts it), unless float arithmetic drifts.
+    pub fn is_consistent(&self) -> bool {
+        (self.first() + self.second() - self.sum).abs() < Self::minimum_precision() * self.sum.abs().max(1.0)
+    }
+
+    /// Whether two pairings have the same sum and numbers within the given tolerance of each other (in either order),
+    /// so the same pairing isn't told apart by rounding error. Unlike `==`, this isn't transitive.
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        let is_close = |left: f64, right: f64| -> bool { left == right || (left - right).abs() <= tolerance };
+        self.sum == other.sum && (is_close(self.first(), other.first()) || is_close(self.first(), other.second()))
+    }
+
     /// Finds the difference between two NumberPairings
     pub fn difference_from(&self, other: &Self) -> f64 { (self.result() - other.result()).abs() }
 
     /// This will test if two results are close enough to be considered equal to each other
     /// The two NumberPairings may still be !=
-    pub fn is_equivalent_to(&self, other: &Self) -> bool { self.difference_from(other) < Self::minimum_precision() }
+    pub fn is_equivalent_to(&self, other: &Self) -> bool { self.is_equivalent_within(other, Self::minimum_precision()) }
+
+    /// This will test if two results are within the given tolerance of each other
+    pub fn is_equivalent_within(&self, other: &Self, tolerance: f64) -> bool { self.difference_from(other) < tolerance }
+
+    /// Finds the difference between two NumberPairings, relative to the other's result,
+    /// so it means the same at any scale (unlike `difference_from`)
+    pub fn relative_difference_from(&self, other: &Self) -> f64 {
+        self.difference_from(other) / other.result().max(f64::MIN_POSITIVE)
+    }
+
+    /// This will test if two results are within the given fraction of the other's result of each other
+    pub fn is_relatively_equivalent_to(&self, other: &Self, relative_tolerance: f64) -> bool {
+        self.relative_difference_from(other) < relative_tolerance
+    }
+
+    #[cfg(feature = "std")]
+    /// Describes the pairing (smaller number first) with numbers rounded to the given precision,
+    /// optionally in scientific notation (useful when the result is very large or small)
+    pub fn format(&self, precision: usize, scientific: bool) -> String {
+        let format_number = |number: f64| -> String {
+            if scientific { format!("{:.*e}", precision, number) } else { format_float(&number, &precision) }
+        };
+        let (low, high) = self.as_sorted();
+        format!(
+            "{} and {} -> {} (difference: {}, product: {} -> result: {})",
+            format_number(low),
+            format_number(high),
+            format_number(self.sum),
+            format_number(self.difference()),
+            format_number(self.product()),
+            format_number(self.result())
+        )
+    }
+
+    #[cfg(feature = "std")]
+    /// Spells out how the result is computed, e.g. `2 × 6 = 12; |2 − 6| = 4; 12 × 4 = 48`
+    pub fn equation(&self) -> String {
+        let (low, high) = self.as_sorted();
+        let format_number = |number: f64| -> String { format_float(&number, &4) };
+        let (product, difference) = (format_number(self.product()), format_number(self.difference()));
+        format!(
+            "{} × {} = {}; |{} − {}| = {}; {} × {} = {}",
+            format_number(low), format_number(high), product,
+            format_number(low), format_number(high), difference,
+            product, difference, format_number(self.result())
+        )
+    }
+
+    /// A copy of the pairing with the first number rounded to the given number of decimal places
+    /// (the sum is kept, so the second number is whatever's left of it).
+    /// An f64 holds no more than 15 significant decimal digits, so more decimal places than that are rounded to 15.
+    pub fn rounded(&self, decimals: u32) -> Self {
+        let factor = math::powi(10.0, decimals.min(15) as i32);
+        let scaled = self.first() * factor;
+        // A number too large to scale has no decimal places left to round
+        let first = if scaled.is_finite() { math::round(scaled) / factor } else { self.first() };
+        Self::new(first, self.sum)
+    }
+
+    /// Compares two NumberPairings by the given objective instead of by `result()`
+    pub fn cmp_by(&self, other: &Self, objective: &dyn Objective) -> Ordering { objective.compare(self, other) }
 
     // Private Methods ------------------------------------------------------- /
 
+    /// Creates a pairing from an already validated number, caching its result
+    /// (every constructor goes through here, so this is where they all check that the numbers add up to the sum)
+    fn from_parts(one_number: f64, sum: f64) -> Self {
+        let mut pairing = Self { one_number, sum, cached_result: 0.0 };
+        pairing.cached_result = pairing.compute_result();
+        debug_assert!(!(one_number.is_finite() && sum.is_finite()) || pairing.is_consistent(), "{:?} doesn't add up to its sum", pairing);
+        pairing
+    }
+
+    /// Computes the result from scratch (the product multiplied by the difference)
+    fn compute_result(&self) -> f64 { self.product() * self.difference() }
+
+    /// The smaller of the two numbers, which is the same for a pairing and its mirror
+    fn low(&self) -> f64 { self.first().min(self.second()) }
+
+    /// Rounds a value down to a whole number of steps of the minimum precision (adding zero turns `-0.0` into `0.0`),
+    /// so values within the same step compare equal, and comparing steps is still a total order
+    fn quantize(value: f64) -> f64 { math::floor(value / Self::minimum_precision()) + 0.0 }
+
     /// This will set a bound to ensures that the number is positive and not more than the sum
     fn validate_and_correct(&self, requested_number: f64) -> f64 {
         let Self { sum, .. } = self;
-        Self::validate_and_correct_input(requested_number, &sum)
+        Self::validate_and_correct_input(requested_number, sum)
     }
 
     // Static ------------------------------------------------------- /
 
+    /// Whether the result can be produced by some pairing of the sum (allowing for float error at the optimum)
+    fn is_achievable(result: f64, sum: f64) -> bool {
+        if result.is_nan() || result < 0.0 || sum.is_nan() || sum < 0.0 {
+            return false;
+        }
+        result <= Self::optimal_for(sum).result() + Self::minimum_precision()
+    }
+
+    /// Bisects the deviations between low and high (where the result is either rising or falling throughout)
+    /// for the one that produces the given result
+    fn deviation_for_result(result: f64, sum: f64, mut low: f64, mut high: f64, is_rising: bool) -> f64 {
+        for _ in 0..200 {
+            let middle = (low + high) / 2.0;
+            if middle <= low || middle >= high {
+                break;
+            }
+            let is_below = Self::from_deviation(sum, middle).result() < result;
+            if is_below == is_rising { low = middle; } else { high = middle; }
+        }
+        (low + high) / 2.0
+    }
+
     /// This will set a bound to ensures that the number is positive and not more than the sum
     fn validate_and_correct_input(requested_number: f64, sum: &f64) -> f64 {
         let non_negative = requested_number.abs();
@@ -71,28 +344,69 @@ impl NumberPairing {
     pub fn default_sum() -> f64 { 8.0 }
     
     /// The minimum level of precision we care about... beyond this point, we'll consider values equal
-    fn minimum_precision() -> f64 { 0.0000000001 }
+    pub fn minimum_precision() -> f64 { MINIMUM_PRECISION }
+}
+
+/// Orders pairings by how central they are (their deviation from the center of the sum), most central first.
+/// An alternative to ordering by result, for use with `sort_by`.
+pub fn cmp_by_deviation(a: &NumberPairing, b: &NumberPairing) -> Ordering {
+    a.deviation().partial_cmp(&b.deviation()).unwrap_or(Ordering::Equal)
+}
+
+/// The reasons a NumberPairing can't be created from the given input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PairingError {
+    /// The number or the sum is infinite or not a number
+    NonFinite,
+    /// The sum is less than zero
+    NegativeSum,
+    /// The requested number is more than the sum
+    NumberExceedsSum,
+    /// The requested number is less than zero
+    NumberNegative,
+}
+
+impl Display for PairingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let message = match self {
+            PairingError::NonFinite => "the number or the sum isn't finite",
+            PairingError::NegativeSum => "the sum is negative",
+            PairingError::NumberExceedsSum => "the number is more than the sum",
+            PairingError::NumberNegative => "the number is negative",
+        };
+        write!(f, "Invalid number pairing: {}", message)
+    }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for PairingError {}
+
+#[cfg(feature = "std")]
 impl Display for NumberPairing {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        let Self { one_number: first, sum } = self;
-        let precision = 4;
-        let first_rnd = format_float(first, &precision);
-        let second = format_float(&self.second(), &precision);
-        let difference = format_float(&self.difference(), &precision);
-        let product = format_float(&self.product(), &precision);
-        let result = format_float(&self.result(), &precision);
-        write!(f, "{} and {} -> {} (difference: {}, product: {} -> result: {})", first_rnd, second, sum, difference, product, result)
+        write!(f, "{}", self.format(4, false))
     }
 }
 
+impl Debug for NumberPairing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.debug_struct("NumberPairing")
+            .field("first", &self.first())
+            .field("second", &self.second())
+            .field("sum", &self.sum)
+            .field("product", &self.product())
+            .field("difference", &self.difference())
+            .field("result", &self.result())
+            .finish()
+    }
+}
+
+/// Pairings are equal if they have the same sum and the same numbers (in either order), within the minimum precision.
+/// This makes `Eq` an approximate equality: the smaller numbers are quantized to the minimum precision and compared by step,
+/// so it stays transitive and consistent with `Hash`. Use `approx_eq` to compare within a distance instead.
 impl PartialEq for NumberPairing {
     fn eq(&self, other: &Self) -> bool {
-        let sums_are_equal = self.sum == other.sum;
-        let stored_are_equal = self.first() == other.first();
-        let stored_is_equal_to_inverse = self.first() == other.second();
-        (sums_are_equal && stored_are_equal) || (sums_are_equal && stored_is_equal_to_inverse)
+        self.sum == other.sum && Self::quantize(self.low()) == Self::quantize(other.low())
     }
 }
 
@@ -105,28 +419,37 @@ impl PartialOrd for NumberPairing {
 }
 
 impl Ord for NumberPairing  {
+    /// Orders by result, quantized to the minimum precision, so near-equal results compare `Equal`.
+    /// Results are compared by the step of the minimum precision they fall in (rather than by their distance apart),
+    /// which keeps this a total order: equivalent results on either side of a step boundary still compare by step.
     fn cmp(&self, other: &Self) -> Ordering {
-        let l_result = self.result();
-        let r_result = other.result();
-        if l_result > r_result {
-            Ordering::Greater
-        } else if l_result < r_result {
-            Ordering::Less
-        } else {
-            Ordering::Equal
-        }
+        Self::quantize(self.result()).total_cmp(&Self::quantize(other.result()))
     }
 }
 
 impl Hash for NumberPairing {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        let Self{ one_number, sum } = self;
-        let (l1 ,l2) = util::split_float_64(one_number);
-        let (r1 ,r2) = util::split_float_64(sum);
-        l1.hash(state);
-        l2.hash(state);
-        r1.hash(state);
-        r2.hash(state);
+        // Mirror pairings and pairings within the minimum precision are equal, so hash what equality compares
+        Self::quantize(self.low()).to_bits().hash(state);
+        (self.sum + 0.0).to_bits().hash(state);
+    }
+}
+
+impl From<NumberPairing> for (f64, f64) {
+    fn from(pairing: NumberPairing) -> Self {
+        (pairing.first(), pairing.second())
+    }
+}
+
+impl TryFrom<(f64, f64)> for NumberPairing {
+    type Error = PairingError;
+
+    /// Creates a pairing of the two numbers, summing to their total
+    fn try_from((first, second): (f64, f64)) -> Result<Self, Self::Error> {
+        if first < 0.0 || second < 0.0 {
+            return Err(PairingError::NumberNegative);
+        }
+        Self::try_new(first, first + second)
     }
 }
 
@@ -137,12 +460,3 @@ impl Clone for NumberPairing {
         *self
     }
 }
-
-mod util {
-    pub fn split_float_64(float_64: &f64) -> (u32, u32) {
-        let pos: f64 = float_64.abs();
-        let whole: u32 = pos as u32;
-        let rem: u32 = ((pos - (pos.round())) * 100_000_000.0) as u32;
-        (whole, rem)
-    } 
-}
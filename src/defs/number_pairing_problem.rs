@@ -1,35 +1,94 @@
-use super::number_pairing::NumberPairing;
+use super::number_pairing::{ NumberPairing, MINIMUM_PRECISION };
+use super::objective::{ Goal, Objective, ProductTimesDifference };
+use super::problem_view::ProblemView;
+use super::solve_report::SolveReport;
+use crate::util::format_float;
+use std::cmp::Ordering;
+use std::ops::ControlFlow;
+use std::sync::Arc;
+use std::sync::mpsc::{ self, Receiver };
+use std::thread::{ self, JoinHandle };
+use std::time::{ Duration, Instant };
+use std::fmt::Debug;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::result::Result;
 use std::fmt::Error;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
-/// Stores the results of a number pairing problem
-pub struct Results {
-    best: f64,
-    best_pairing: Vec<NumberPairing>,
-    other: Option<Vec<NumberPairing>>,
-}
+mod builder;
+mod results;
+pub use builder::NumberPairingProblemBuilder;
+pub use results::{ MergeError, Results };
+
+/// The default limit on recursion runs
+pub const DEFAULT_MAX_RUNS: u32 = 40;
+
+/// By default, each run searches half its step size either side of the best number so far
+pub const DEFAULT_MARGIN_FACTOR: f64 = 0.5;
+
+/// By default, each run's step size is the last one divided by this (times the run count)
+pub const DEFAULT_SHRINK_FACTOR: f64 = 4.0;
+
+/// With an adaptive precision schedule, an improvement this many orders of magnitude above the tolerance
+/// shrinks the step size by exactly the usual amount
+const ADAPTIVE_PIVOT: f64 = 6.0;
+
+/// Other results are only collected from search runs at least this coarse,
+/// so the finer runs don't flood them with near-duplicates of the best result
+const OTHER_RESULTS_PRECISION_FLOOR: f64 = 0.01;
+
+/// The most other (non-best) results shown when a solved problem is presented, unless set otherwise
+pub const DEFAULT_DISPLAY_LIMIT: usize = 10;
+
+/// The decimal places numbers are shown to when a solved problem is presented, unless set otherwise
+pub const DEFAULT_DISPLAY_PRECISION: usize = 4;
+
+/// Called after each recursion run with the run count and the overall best pairing so far.
+/// Returning `ControlFlow::Break` stops the search early.
+type RunCallback<'a> = dyn FnMut(u32, &NumberPairing) -> ControlFlow<()> + 'a;
 
 /// # NumberPairingProblem
 /// A structure to define a problem by which takes two numbers that
 /// sum to a given amount (default to 8). The problem must find the largest
 /// number combination (determined by multiplying the difference by the
-/// product of the two numbers).
+/// product of the two numbers, or by a custom `Objective`).
+#[derive(Clone)]
 pub struct NumberPairingProblem {
     pub sum: f64,
     pub results: Option<Results>,
-    pub runs_to_solve: u32,
 
     // Private ------------------------ /
 
-    // Sh
+    // How many search runs it took to solve (only set by solving)
+    runs_to_solve: u32,
+
+    // Should other (non-best) results be collected during the search?
     collect_other_results: bool,
 
-    // This is a NumberPairing instance that will always have a result of 0.
-    // We will use this as the initial high NumberPairing to beat.
+    // If set, the problem is solved analytically instead of searched numerically
+    // (when the objective has a known analytic optimum)
+    exact: bool,
+
+    // If set, the first search run's grid is shifted to land on the objective's known optimum (if it has one)
+    seeded: bool,
+
+    // The value being optimized by the search
+    // (shared, so a cloned problem uses the same objective)
+    objective: Arc<dyn Objective>,
+
+    // Whether the objective is maximized or minimized
+    goal: Goal,
+
+    // This is a NumberPairing instance (0 and the sum) that will always have a result of 0.
+    // We will use this as the initial high NumberPairing to beat in each run,
+    // and the overall best result starts as a copy of it, so it's the only starting point.
     initial_high_value: NumberPairing,
 
+    // The step size used for the first search run
+    initial_precision: f64,
+
     // These constants for lower and upper bounds set the boundaries for
     // numbers in the number pairing. We will use these to ensure we don't
     // get a NumberPairing with a number outside of these bounds.
@@ -43,6 +102,37 @@ pub struct NumberPairingProblem {
     best_results: Vec<NumberPairing>,
     other_results: Option<Vec<NumberPairing>>,
 
+    // Scratch buffers for the best and other results of each run, reused (rather than reallocated) across runs
+    seq_best_buffer: Vec<NumberPairing>,
+    seq_other_buffer: Vec<NumberPairing>,
+
+    // When tracing, every pairing evaluated by the search is recorded here, in order
+    trace: Option<Vec<NumberPairing>>,
+
+    // Convergence metrics from the last search run
+    final_precision: f64,
+    last_improvement: f64,
+
+    // The step size of the run that found the overall best result, which the optimum is within (for a unimodal objective)
+    best_found_precision: f64,
+
+    // How far around the best number each run searches, and how quickly the step size shrinks, between runs
+    margin_factor: f64,
+    shrink_factor: f64,
+
+    // Whether the step size shrinks faster the smaller the last improvement was
+    adaptive_precision: bool,
+
+    // Results closer than this are considered equal, so the search stops once it can't improve by more
+    minimum_precision: f64,
+
+    // The minimum precision scaled to the size of the problem (as if it were solved at a sum of 1),
+    // when the objective scales with the sum, so it's used to compare results
+    result_tolerance: f64,
+
+    // Other results closer than this to a better one are dropped as near-duplicates
+    dedup_tolerance: f64,
+
     // This is a failsafe. Hopefully, we end recursion before we get here,
     // but just in case, it sets a limit on recursion
     run_count: u32,
@@ -54,114 +144,358 @@ impl NumberPairingProblem {
     // Initializers ---------------------------------------------------------- /
 
     /// Solves the problem with given sum.
-    pub fn solve_with(sum: f64, collect_other_results: bool) -> Self {
-        let initial_high_value = NumberPairing::new(0.0, sum);
-        let mut problem = Self {
-            sum,
-            runs_to_solve: 0,
-            results: None,
-            collect_other_results,
-            initial_high_value,
-            lower_bounds: 0.0,
-            upper_bounds: sum / 2.0,
-            overall_best_result: initial_high_value,
-            best_results: Vec::new(),
-            other_results: if collect_other_results { Some(Vec::new()) } else { None },
-            run_count: 0,
-            max_runs: 40,
-        };
+    /// When `exact` is set, the closed-form solution is used instead of the numeric search
+    /// (if the objective has one, and the goal is to maximize it).
+    pub fn solve_with<O: Objective + 'static>(sum: f64, collect_other_results: bool, exact: bool, objective: O, goal: Goal) -> Self {
+        Self::builder()
+            .collect_other_results(collect_other_results)
+            .exact(exact)
+            .objective(objective)
+            .goal(goal)
+            .solve(sum)
+    }
+
+    /// Solves the problem with the default sum of 8.
+    pub fn solve_default() -> Self {
+        Self::solve_with(8.0, true, false, ProductTimesDifference, Goal::Maximize)
+    }
+
+    /// Solves the problem with given sum by golden-section search instead of the recursive sweep.
+    /// `runs_to_solve` reports the number of pairings evaluated.
+    pub fn solve_golden_section(sum: f64, collect_other_results: bool) -> Self {
+        let mut problem = Self::builder()
+            .collect_other_results(collect_other_results)
+            .build(sum);
+        problem.search_golden_section();
+        problem.collect_results();
+        problem
+    }
 
+    /// Solves a problem for each of the given sums, independently and in the same order.
+    pub fn solve_batch(sums: &[f64], collect_other_results: bool) -> Vec<Self> {
+        sums.iter()
+            .map(|sum| Self::solve_with(*sum, collect_other_results, false, ProductTimesDifference, Goal::Maximize))
+            .collect()
+    }
+
+    /// Solves a problem for each of the given sums in parallel, in the same order as the sums.
+    /// Each solve is independent, so no state is shared between threads.
+    #[cfg(feature = "rayon")]
+    pub fn solve_batch_parallel(sums: &[f64], collect_other_results: bool) -> Vec<Self> {
+        sums.par_iter()
+            .map(|sum| Self::solve_with(*sum, collect_other_results, false, ProductTimesDifference, Goal::Maximize))
+            .collect()
+    }
+
+    /// Solves the problem with given sum, also returning every pairing the search evaluated,
+    /// in evaluation order. This shows how the search narrows toward the optimum.
+    pub fn solve_traced(sum: f64) -> (Self, Vec<NumberPairing>) {
+        let mut problem = Self::builder().build(sum);
+        problem.trace = Some(Vec::new());
         problem.solve();
+        let trace = problem.trace.take().unwrap_or_default();
+        (problem, trace)
+    }
+
+    /// Solves the problem with given sum, calling the callback after each recursion run with
+    /// the run count and the overall best pairing so far (e.g. to show progress).
+    /// Returning `ControlFlow::Break` from the callback stops the search at that run.
+    pub fn solve_with_callback<F>(sum: f64, collect_other_results: bool, mut callback: F) -> Self
+    where F: FnMut(u32, &NumberPairing) -> ControlFlow<()> {
+        let mut problem = Self::builder()
+            .collect_other_results(collect_other_results)
+            .build(sum);
+        problem.solve_observed(&mut callback);
         problem
     }
 
-    /// Solves the problem with the default sum of 8.
-    pub fn solve_default() -> Self {
-        Self::solve_with(8.0, true)
+    /// Solves the problem with given sum, also returning the run count and the best result so far
+    /// at the end of each search run (e.g. to plot how the search converges)
+    pub fn solve_with_history(sum: f64, collect_other_results: bool) -> (Self, Vec<(u32, f64)>) {
+        let mut history: Vec<(u32, f64)> = Vec::new();
+        let problem = Self::solve_with_callback(sum, collect_other_results, |run_count, best| {
+            history.push((run_count, best.result()));
+            ControlFlow::Continue(())
+        });
+        (problem, history)
+    }
+
+    /// Solves the problem with given sum on another thread, sending an event with the run count and
+    /// the best result so far after each recursion run, then the results once it's solved.
+    /// The channel closes once the results are sent. If the receiver is dropped, the search stops at the next run.
+    /// Join the returned handle to wait for the thread to finish (and to see if it panicked).
+    pub fn solve_streaming(sum: f64, collect_other_results: bool) -> (Receiver<SolveEvent>, JoinHandle<()>) {
+        let (sender, receiver) = mpsc::channel();
+        let handle = thread::spawn(move || {
+            let problem = Self::solve_with_callback(sum, collect_other_results, |count, best| {
+                match sender.send(SolveEvent::Run { count, best: best.result() }) {
+                    Ok(()) => ControlFlow::Continue(()),
+                    Err(_) => ControlFlow::Break(()),
+                }
+            });
+            if let Some(results) = problem.results {
+                let _ = sender.send(SolveEvent::Done(results));
+            }
+        });
+        (receiver, handle)
+    }
+
+    /// Solves the problem with given sum, only searching first numbers within the given band
+    /// (clamped to zero through the sum). The best result is the best within the band,
+    /// which is at one of its edges if the band doesn't include the optimum.
+    /// If no part of the band is within zero through the sum, the problem is left unsolved.
+    pub fn solve_constrained(sum: f64, first_min: f64, first_max: f64, collect_other_results: bool) -> Self {
+        let lower = first_min.min(first_max).max(0.0);
+        let upper = first_min.max(first_max).min(sum);
+        let builder = Self::builder()
+            .collect_other_results(collect_other_results)
+            .bounds(lower, upper);
+        if lower.is_nan() || upper.is_nan() || lower > upper {
+            return builder.build(sum);
+        }
+        builder.solve(sum)
+    }
+
+    /// Solves the problem with given sum, stopping after the run in progress once the timeout has passed.
+    /// The first run always completes, so there's always a best result, if an imprecise one.
+    pub fn solve_with_timeout(sum: f64, collect_other_results: bool, timeout: Duration) -> Self {
+        let start = Instant::now();
+        Self::solve_with_callback(sum, collect_other_results, |_, _| {
+            if start.elapsed() >= timeout { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+        })
+    }
+
+    /// Solves the problem with given sum (with the default settings), returning an error rather than
+    /// a meaningless result if the sum is not a finite, positive number, or is so large the result overflows
+    pub fn solve_checked(sum: f64) -> Result<Self, SolveError> {
+        if !sum.is_finite() {
+            Err(SolveError::NonFiniteSum)
+        } else if sum <= 0.0 {
+            Err(SolveError::NonPositiveSum)
+        } else if NumberPairing::optimal_for(sum).checked_result().is_none() {
+            Err(SolveError::ResultOverflows)
+        } else {
+            Ok(Self::builder().solve(sum))
+        }
+    }
+
+    /// Starts configuring a problem, to be solved with `NumberPairingProblemBuilder::solve`.
+    pub fn builder() -> NumberPairingProblemBuilder {
+        NumberPairingProblemBuilder::new()
     }
 
     // Methods --------------------------------------------------------------- /
 
+    /// The results, if the problem has been solved
+    pub fn results(&self) -> Option<&Results> { self.results.as_ref() }
+
+    /// How many search runs it took to solve the problem (zero until solved)
+    pub fn runs_to_solve(&self) -> u32 { self.runs_to_solve }
+
+    /// Whether the problem has been solved (so there are results)
+    pub fn is_solved(&self) -> bool { self.results.is_some() }
+
+    /// Checks the best value found against the objective's analytic optimum, returning whether they agree
+    /// within the given tolerance. An unsolved problem never verifies, and nor does one whose objective
+    /// has no known optimum for its goal (there's only one for maximizing, and only for some objectives).
+    pub fn verify(&self, tolerance: f64) -> bool {
+        let optimum = if self.goal == Goal::Maximize { self.objective.analytic_optimum(self.sum) } else { None };
+        match (&self.results, optimum) {
+            (Some(results), Some(optimum)) => (results.best - self.objective.evaluate(&optimum)).abs() <= tolerance,
+            _ => false,
+        }
+    }
+
+    /// Compares two problems by their best values (so usually, the larger sum is greater).
+    /// An unsolved problem is less than any solved one, and two unsolved problems are equal.
+    pub fn compare(&self, other: &NumberPairingProblem) -> Ordering {
+        match (self.results(), other.results()) {
+            (Some(left), Some(right)) => left.best().partial_cmp(&right.best()).unwrap_or(Ordering::Equal),
+            (Some(_), None) => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (None, None) => Ordering::Equal,
+        }
+    }
+
+    /// A one-line summary of the problem, e.g. `sum=8: best=49.2672 at (1.6906, 6.3094) in 2 runs`,
+    /// for compact logging when solving many problems
+    pub fn summary(&self) -> String {
+        let format_number = |number: f64| -> String { format_float(&number, &4) };
+        let sum = format_number(self.sum);
+        match self.results().map(|results| (results.best(), results.best_pairing().first())) {
+            Some((best, Some(pairing))) => {
+                let (low, high) = pairing.as_sorted();
+                let runs_str = if self.runs_to_solve == 1 { "run" } else { "runs" };
+                format!("sum={}: best={} at ({}, {}) in {} {}", sum, format_number(best), format_number(low), format_number(high), self.runs_to_solve, runs_str)
+            }
+            _ => format!("sum={}: not yet solved", sum),
+        }
+    }
+
+    /// A displayable view of the problem, which can be configured (e.g. to show more other results)
+    pub fn view(&self) -> ProblemView<'_> { ProblemView::new(self) }
+
+    /// A displayable view of the problem showing at most the given number of other results
+    pub fn display_limit(&self, display_limit: usize) -> ProblemView<'_> { self.view().display_limit(display_limit) }
+
+    /// A displayable report comparing the best value found to the analytic optimum
+    pub fn report(&self) -> SolveReport<'_> { SolveReport::new(self) }
+
     /// Solves the problem
     fn solve(&mut self) {
-        let precision = self.sum / 4.0;
-        self.get_highest_result_of_seq(self.lower_bounds, self.upper_bounds, precision);
+        self.solve_observed(&mut |_, _| ControlFlow::Continue(()));
+    }
+
+    /// Solves the problem, reporting each recursion run to the callback
+    fn solve_observed(&mut self, on_run: &mut RunCallback<'_>) {
+        let analytic_optimum = if self.exact && self.goal == Goal::Maximize { self.objective.analytic_optimum(self.sum) } else { None };
+        if let Some(optimum) = analytic_optimum {
+            // No search needed, so there are no other results to collect
+            self.overall_best_result = optimum;
+            self.best_results.push(optimum);
+            self.other_results = None;
+        } else {
+            // The overall best starts as the initial high value, so it's the best pairing until a run beats it
+            // (which no run does if the objective is best at that end)
+            self.best_results.push(self.initial_high_value);
+            let (low, high) = self.first_run_range();
+            self.get_highest_result_of_seq(low, high, self.initial_precision, on_run);
+        }
         self.collect_results();
     }
 
+    /// The range for the first search run, which normally spans the bounds.
+    /// When seeded, the range is shifted (by less than a step) so the first run steps right onto
+    /// The range still spans the bounds (to within a step), and later runs may search out to the bounds, so a bad guess is still caught.
+    /// The range still spans the bounds, so a bad guess is still caught by the first run.
+    fn first_run_range(&self) -> (f64, f64) {
+        let lower = self.lower_bounds;
+        let upper = self.upper_bounds;
+        let precision = self.initial_precision;
+        let guess = if self.seeded && self.goal == Goal::Maximize { self.objective.analytic_optimum(self.sum) } else { None };
+        match guess.map(|pairing| pairing.first()) {
+            Some(guess) if guess >= lower && guess <= upper && precision > 0.0 => {
+                let low = guess - ((guess - lower) / precision).floor() * precision;
+                let high = guess + ((upper - guess) / precision).floor() * precision;
+                (low, high)
+            }
+            _ => (lower, upper),
+        }
+    }
+
     /// This is a recursive function that will start with low precision, look for the max value,
     /// then continue looking for higher max values (at a higher precision) around that max value.
     /// When further recursion no longer finds a better value, recursion ends
     /// (as the max value has been found)
-    fn get_highest_result_of_seq(&mut self, low: f64, high: f64, precision: f64) {
+    fn get_highest_result_of_seq(&mut self, low: f64, high: f64, precision: f64, on_run: &mut RunCallback<'_>) {
         let Self {
             collect_other_results,
             initial_high_value,
             max_runs,
+            objective,
+            goal,
+            result_tolerance,
             ..
         } = self;
 
-        if self.run_count >= *max_runs { return };
+        if self.run_count >= *max_runs {
+            #[cfg(feature = "logging")]
+            log::debug!("Stopping at the limit of {} runs", max_runs);
+            self.runs_to_solve = self.run_count;
+            return;
+        }
         self.run_count += 1;
 
         // We will set three local variables that will be for each recursive run...
         // These will be compared to the overall variables for the method
         let mut seq_best_result: NumberPairing = *initial_high_value;
-        let mut best_results_of_seq: Vec<NumberPairing> = Vec::new();
-        let mut other_results_of_seq: Option<Vec<NumberPairing>> = if *collect_other_results {
-            Some(Vec::new())
-        } else {
-            None
-        };
+        let mut best_results_of_seq: Vec<NumberPairing> = std::mem::take(&mut self.seq_best_buffer);
+        let mut other_results_of_seq: Vec<NumberPairing> = std::mem::take(&mut self.seq_other_buffer);
+        best_results_of_seq.clear();
+        other_results_of_seq.clear();
 
         // Closure to determine if we can add to the other sequence
         let can_be_added_to_other = |pairing: &NumberPairing| -> bool {
-            pairing != initial_high_value && precision >= 0.01 && *collect_other_results
+            pairing != initial_high_value && precision >= OTHER_RESULTS_PRECISION_FLOOR && *collect_other_results
         };
 
         // Set the search range and loop through each value in it
-        let multiplier = 100_000_000.0;
-        let conversion = (1.0 / precision) * &multiplier;
-        let low_bound = (low * conversion).round() as usize;
-        let high_bound = (high * conversion).round() as usize;
-        for i in (low_bound..=high_bound).step_by(multiplier as usize) {
-            let number = i as f64 / conversion;
+        // We step in f64 with an explicit step count (rather than scaling the range to integers),
+        // so large sums or fine precision can't overflow the loop bounds
+        let step_count = ((high - low) / precision).floor();
+        if !step_count.is_finite() || step_count < 0.0 {
+            // The range can't be stepped through at this precision, so we can't search any further
+            self.runs_to_solve = self.run_count;
+            return;
+        }
+        #[cfg(feature = "logging")]
+        log::debug!("Run {}: searching {} to {} in steps of {}", self.run_count, low, high, precision);
+        for step in 0..=(step_count as u64) {
+            let number = low + step as f64 * precision;
 
             // Create a new NumberPairing to evaluate
             let this_result = NumberPairing::new(number, self.sum);
-            // println!("{}", this_result);
-            if &this_result > &seq_best_result {
+            #[cfg(feature = "logging")]
+            log::trace!("Run {}: evaluated {:?}", self.run_count, this_result);
+            if let Some(trace) = &mut self.trace {
+                trace.push(this_result);
+            }
+            let is_tied = objective.is_equivalent_within(&this_result, &seq_best_result, *result_tolerance);
+            let comparison = goal.orient(objective.compare(&this_result, &seq_best_result));
+            if comparison == Ordering::Greater && !is_tied {
                 // If the new Result is better than any other in the sequence, it's the new max
                 // We'll set it to the best in sequence and move and previous best results to the other results array
                 // Then add the new result to the best results array
                 seq_best_result = this_result;
-                best_results_of_seq
-                    .iter()
-                    .for_each(|result| {
-                        if can_be_added_to_other(result) {
-                            if let Some(other) = &mut other_results_of_seq {
-                                other.push(*result);
-                            }
-                        }
-                    });
-                best_results_of_seq.clear();
+                other_results_of_seq.extend(best_results_of_seq.drain(..).filter(|result| can_be_added_to_other(result)));
                 best_results_of_seq.push(seq_best_result);
-            } else if &this_result == &seq_best_result {
-                // If we found a NumberPairing that matches, but doesn't exceed, the existing best, we'll add it to the best results array
-                best_results_of_seq.push(this_result);
-
+            } else if is_tied {
+                // If we found a NumberPairing that's equivalent to (within the minimum precision of), but not clearly better than,
+                // the existing best, we'll add it to the best results array (unless it's a mirror of one already there)
+                if !best_results_of_seq.iter().any(|kept| kept.approx_eq(&this_result, MINIMUM_PRECISION)) {
+                    best_results_of_seq.push(this_result);
+                }
             } else if can_be_added_to_other(&this_result) {
                 // Else, we'll just add it to the other results array
-                if let Some(other) = &mut other_results_of_seq {
-                    other.push(this_result);
-                }
+                other_results_of_seq.push(this_result);
             }
         }
 
+        // Record how far this run got, in case it's the last one
+        let seq_value = objective.evaluate(&seq_best_result);
+        let overall_value = objective.evaluate(&self.overall_best_result);
+        let improvement = match goal {
+            Goal::Maximize => seq_value - overall_value,
+            Goal::Minimize => overall_value - seq_value,
+        };
+        self.final_precision = precision;
+        self.last_improvement = improvement.max(0.0);
+
         // When the best result from the sequence is lower or equal to the overall result (or close enough), we found the max and can stop
-        let condition_to_end_recursion = seq_best_result <= self.overall_best_result || seq_best_result.is_equivalent_to(&self.overall_best_result);
+        // (If it isn't better at all, the search stalled, rather than converged, so the optimum may lie between the grid points)
+        let is_stalled = goal.orient(objective.compare(&seq_best_result, &self.overall_best_result)) != Ordering::Greater;
+        let condition_to_end_recursion = is_stalled
+            || objective.is_equivalent_within(&seq_best_result, &self.overall_best_result, *result_tolerance);
         if condition_to_end_recursion {
+            #[cfg(feature = "logging")]
+            log::debug!("Run {}: no improvement on {:?}, so the search is done", self.run_count, self.overall_best_result);
             self.runs_to_solve = self.run_count;
+            // The last run didn't beat the overall best, but what it found is still kept as other results
+            // (a seeded search lands on the optimum in its first run, so most of its other results come from here)
+            if let Some(other_globals) = &mut self.other_results {
+                let overall_best_result = self.overall_best_result;
+                other_globals.extend(best_results_of_seq.iter().filter(|result| {
+                    can_be_added_to_other(result) && !objective.is_equivalent_within(result, &overall_best_result, *result_tolerance)
+                }));
+                other_globals.append(&mut other_results_of_seq);
+            }
+            self.seq_best_buffer = best_results_of_seq;
+            self.seq_other_buffer = other_results_of_seq;
+            if is_stalled {
+                self.refine_stalled_search();
+            }
+            // The final run is reported too, though the search is ending either way
+            let _ = on_run(self.run_count, &self.overall_best_result);
             return;
         }
 
@@ -169,44 +503,185 @@ impl NumberPairingProblem {
         // We'll also move the previous best results from the best results array to the other results array
         // and add the new best results to the best results array
         self.overall_best_result = seq_best_result;
+        self.best_found_precision = precision;
+        #[cfg(feature = "logging")]
+        log::debug!("Run {}: new overall best {:?} (improved by {})", self.run_count, seq_best_result, self.last_improvement);
 
-        self.best_results
-            .iter()
-            .for_each(|result| {
-                if can_be_added_to_other(result) {
-                    if let Some(other) = &mut other_results_of_seq {
-                        other.push(*result);
-                    }
-                }
-            });
-        self.best_results.clear();
-        self.best_results.append(&mut best_results_of_seq);
-        if let Some(other) = &mut other_results_of_seq {
-            if let Some(other_globals) = &mut self.other_results {
-                other_globals.append(other);
-            }
+        other_results_of_seq.extend(self.best_results.iter().filter(|result| can_be_added_to_other(result)));
+        std::mem::swap(&mut self.best_results, &mut best_results_of_seq);
+        if let Some(other_globals) = &mut self.other_results {
+            other_globals.append(&mut other_results_of_seq);
+        }
+        self.seq_best_buffer = best_results_of_seq;
+        self.seq_other_buffer = other_results_of_seq;
+
+        // Let the caller know how the search is going, and stop if they ask us to
+        if on_run(self.run_count, &self.overall_best_result).is_break() {
+            self.runs_to_solve = self.run_count;
+            return;
         }
+
         // This finds what the first number was from the best result. This the number we'll target when call the function again
         let best_number_of_seq: f64 = self.overall_best_result.first();
         // We will run the function again with more precision...
-        let new_precision: f64 = precision / ((self.run_count * 4) as f64);
-        // We'll look to half the current precision on either side of the best value
-        let margin_to_search_around_best_value: f64 = precision / 2.0;
+        let new_precision: f64 = precision / self.precision_divisor();
+        // We'll look to (by default) half the current precision on either side of the best value
+        let margin_to_search_around_best_value: f64 = precision * self.margin_factor;
         // ... but we'll look in a smaller range. The new result will be the best number from the sequence minus the shrink amount
         let mut new_low_value = best_number_of_seq - margin_to_search_around_best_value;
-        if new_low_value < low {
+        if new_low_value < self.lower_bounds {
             // If new start is lower than lower bounds, snap it to lower bounds
-            new_low_value = low;
+            // (rather than to this run's start, which a seeded first run shifts off the bounds)
+            new_low_value = self.lower_bounds;
         }
         // ... and new end is the best number in the sequence plus the shrink amount
         let mut new_high_value = best_number_of_seq + margin_to_search_around_best_value;
-        if new_high_value > high {
+        if new_high_value > self.upper_bounds {
             // If new end is higher than upper bounds, snap it to upper bounds
-            new_high_value = high;
+            new_high_value = self.upper_bounds;
         }
 
         // Call recursive function again with narrower range as defined above (but higher precision)
-        self.get_highest_result_of_seq(new_low_value, new_high_value, new_precision);
+        self.get_highest_result_of_seq(new_low_value, new_high_value, new_precision, on_run);
+    }
+
+    /// How much the step size shrinks after the current run: the run count times the shrink factor,
+    /// scaled (when adaptive) by how close the last improvement came to the tolerance results are compared with.
+    /// Each order of magnitude it's above the tolerance halves the extra shrinking, and large improvements
+    /// shrink it less than usual, so the search doesn't narrow in on a number far from the optimum.
+    fn precision_divisor(&self) -> f64 {
+        let divisor = self.run_count as f64 * self.shrink_factor;
+        if !self.adaptive_precision || self.last_improvement <= 0.0 {
+            return divisor;
+        }
+        let orders_above_tolerance = (self.last_improvement / self.result_tolerance).log10();
+        let scale = 2_f64.powf((ADAPTIVE_PIVOT - orders_above_tolerance) / 2.0).clamp(0.5, 16.0);
+        divisor * scale
+    }
+
+    /// When a run can't improve on the best result, the optimum may still lie between the grid points
+    /// (if the grid happened to straddle the peak). For a unimodal objective, the optimum is within a
+    /// step (of the run that found it) of the best number, so a ternary search around each best pairing checks for it.
+    /// If the best of these is better by more than the tolerance, the refined pairings that tie with it become the best.
+    fn refine_stalled_search(&mut self) {
+        let precision = self.best_found_precision;
+        let mut refined_results: Vec<(NumberPairing, f64)> = Vec::new();
+        for index in 0..self.best_results.len() {
+            let best_number = self.best_results[index].first();
+            if let Some(refined) = self.refine_around(best_number, precision) {
+                refined_results.push(refined);
+            }
+        }
+        let (goal, objective) = (self.goal, Arc::clone(&self.objective));
+        let best_refined = refined_results.iter()
+            .map(|(pairing, _)| *pairing)
+            .max_by(|left, right| goal.orient(objective.compare(left, right)));
+        let refined = match best_refined {
+            Some(refined) => refined,
+            None => return,
+        };
+        let is_better = goal.orient(objective.compare(&refined, &self.overall_best_result)) == Ordering::Greater
+            && !objective.is_equivalent_within(&refined, &self.overall_best_result, self.result_tolerance);
+        if !is_better {
+            return;
+        }
+        #[cfg(feature = "logging")]
+        log::debug!("Refined the stalled search to {:?}", refined);
+        let improvement = (objective.evaluate(&refined) - objective.evaluate(&self.overall_best_result)).abs();
+        if let Some(other_results) = &mut self.other_results {
+            if precision >= OTHER_RESULTS_PRECISION_FLOOR {
+                let initial_high_value = self.initial_high_value;
+                other_results.extend(self.best_results.iter().filter(|result| **result != initial_high_value));
+            }
+        }
+        self.best_results.clear();
+        for (pairing, width) in refined_results {
+            if objective.is_equivalent(&pairing, &refined) {
+                self.best_results.push(pairing);
+                if pairing == refined {
+                    self.final_precision = width;
+                }
+            }
+        }
+        self.overall_best_result = refined;
+        self.last_improvement = improvement;
+    }
+
+    /// Ternary searches within the given precision of the number (and within the bounds),
+    /// returning the pairing found and the width of the range it was narrowed to.
+    /// Returns `None` if none of that range is within the bounds.
+    fn refine_around(&mut self, number: f64, precision: f64) -> Option<(NumberPairing, f64)> {
+        let sum = self.sum;
+        let mut left = (number - precision).max(self.lower_bounds);
+        let mut right = (number + precision).min(self.upper_bounds);
+        if left.is_nan() || right.is_nan() || left > right {
+            return None;
+        }
+        let tolerance = (self.upper_bounds - self.lower_bounds).abs() * self.minimum_precision;
+        while (right - left).is_finite() && right - left > tolerance {
+            let third = (right - left) / 3.0;
+            if left + third == left || right - third == right {
+                // The range can't be narrowed any further at this float precision
+                break;
+            }
+            let left_third = NumberPairing::new(left + third, sum);
+            let right_third = NumberPairing::new(right - third, sum);
+            if let Some(trace) = &mut self.trace {
+                trace.push(left_third);
+                trace.push(right_third);
+            }
+            if self.goal.orient(self.objective.compare(&left_third, &right_third)) == Ordering::Less {
+                left += third;
+            } else {
+                right -= third;
+            }
+        }
+        Some((NumberPairing::new((left + right) / 2.0, sum), right - left))
+    }
+
+    /// Golden-section search shrinks the search range by the same ratio (1/φ) with each evaluation,
+    /// keeping whichever side holds the better of two interior points. Since the objective is
+    /// unimodal over the half-interval, this converges on the max in O(log(1/ε)) evaluations.
+    fn search_golden_section(&mut self) {
+        let inverse_phi = (5.0_f64.sqrt() - 1.0) / 2.0;
+        let sum = self.sum;
+        let mut low = self.lower_bounds;
+        let mut high = self.upper_bounds;
+        let tolerance = (high - low) * NumberPairing::minimum_precision();
+        let is_right_better = |left: &NumberPairing, right: &NumberPairing| -> bool {
+            self.goal.orient(self.objective.compare(left, right)) == Ordering::Less
+        };
+
+        let mut left = NumberPairing::new(high - inverse_phi * (high - low), sum);
+        let mut right = NumberPairing::new(low + inverse_phi * (high - low), sum);
+        let mut evaluated: Vec<NumberPairing> = vec![left, right];
+
+        // An infinite or NaN range would never shrink, so only search finite ranges
+        while (high - low).is_finite() && high - low > tolerance {
+            if is_right_better(&left, &right) {
+                // The max is to the right of the left point
+                low = left.first();
+                left = right;
+                right = NumberPairing::new(low + inverse_phi * (high - low), sum);
+                evaluated.push(right);
+            } else {
+                // The max is to the left of the right point
+                high = right.first();
+                right = left;
+                left = NumberPairing::new(high - inverse_phi * (high - low), sum);
+                evaluated.push(left);
+            }
+        }
+
+        let best = if is_right_better(&left, &right) { right } else { left };
+        self.final_precision = high - low;
+        self.run_count = evaluated.len() as u32;
+        self.runs_to_solve = self.run_count;
+        self.overall_best_result = best;
+        self.best_results.push(best);
+        if let Some(other_results) = &mut self.other_results {
+            other_results.extend(evaluated.into_iter().filter(|pairing| *pairing != best));
+        }
     }
 
     /// Collects results into public structure.
@@ -214,58 +689,188 @@ impl NumberPairingProblem {
         let Self {
             overall_best_result,
             best_results,
+            objective,
+            goal,
+            dedup_tolerance,
             ..
         } = self;
 
-        // Sort the other results
+        // Sort the other results, best first
         let mut others_sorted: Option<Vec<NumberPairing>> = None;
         if let Some(other_results) = &mut self.other_results {
-            other_results.sort_unstable_by(|a, b| b.cmp(a));
-            other_results.dedup();
+            other_results.sort_unstable_by(|a, b| goal.orient(b.cmp_by(a, objective.as_ref())));
+            // Near-equal results (within the dedup tolerance) would only clutter the list, so keep just the first of them
+            other_results.dedup_by(|later, kept| objective.is_equivalent_within(later, kept, *dedup_tolerance));
             let mut sorted: Vec<NumberPairing> = Vec::new();
             sorted.append(other_results);
             others_sorted = Some(sorted);
         }
 
-        let best = overall_best_result.result();
+        let best = objective.evaluate(overall_best_result);
+        // A pairing and its mirror are equal (allowing for rounding error), so only keep the first of each
         let mut best_pairing: Vec<NumberPairing> = Vec::new();
-        best_pairing.append(best_results);
+        for pairing in best_results.drain(..) {
+            if !best_pairing.iter().any(|kept| kept.approx_eq(&pairing, MINIMUM_PRECISION)) {
+                best_pairing.push(pairing);
+            }
+        }
+        // The best pairings all tie, so order them by their first number, rather than the order they were found in
+        best_pairing.sort_by(|a, b| a.first().partial_cmp(&b.first()).unwrap_or(Ordering::Equal));
         let other = others_sorted;
 
         // Return the results
         let results = Results {
             best,
             best_pairing,
-            other
+            other,
+            final_precision: self.final_precision,
+            last_improvement: self.last_improvement,
+            objective: Arc::clone(&self.objective),
+            goal: self.goal,
+            result_tolerance: self.result_tolerance,
         };
         self.results = Some(results);
     }
+
+    // Static ---------------------------------------------------------------- /
+
+    /// Finds the exact optimal pairing for the given sum without searching.
+    /// Writing the two numbers as `sum / 2 ± t`, the result is `(sum² / 4 − t²) · 2t`,
+    /// which is maximized where its derivative is zero, at `t = sum / (2√3)`.
+    pub fn solve_analytic(sum: f64) -> NumberPairing {
+        NumberPairing::optimal_for(sum)
+    }
+
+    /// Samples the result as the first number sweeps evenly from 0 to the sum (both ends included),
+    /// returning `(first number, result)` points, e.g. for plotting.
+    /// Fewer than 2 points returns just the two endpoints.
+    pub fn sample_curve(sum: f64, points: usize) -> Vec<(f64, f64)> {
+        let points = points.max(2);
+        let step = sum / (points - 1) as f64;
+        (0..points)
+            .map(|index| {
+                // Pin the last point to the sum itself, so rounding can't leave it short
+                let number = if index == points - 1 { sum } else { index as f64 * step };
+                (number, NumberPairing::new(number, sum).result())
+            })
+            .collect()
+    }
+
+    /// Renders the result curve (as the first number sweeps from 0 to the sum) as a standalone SVG,
+    /// scaled to fill the given size, with the maximum (on both sides, as the curve is symmetric) marked.
+    pub fn to_svg(sum: f64, width: u32, height: u32) -> String {
+        let optimum = Self::solve_analytic(sum);
+        let highest = optimum.result();
+        let (width_f64, height_f64) = (width as f64, height as f64);
+        // Map the curve onto the image, with the y-axis flipped so higher results are further up
+        let to_x = |number: f64| -> f64 { if sum > 0.0 { number / sum * width_f64 } else { 0.0 } };
+        let to_y = |result: f64| -> f64 { if highest > 0.0 { height_f64 - result / highest * height_f64 } else { height_f64 } };
+        let points: Vec<String> = Self::sample_curve(sum, (width as usize).max(2))
+            .iter()
+            .map(|(number, result)| format!("{:.2},{:.2}", to_x(*number), to_y(*result)))
+            .collect();
+        let mut svg = format!("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n", width, height, width, height);
+        svg.push_str(&format!("  <polyline fill=\"none\" stroke=\"black\" points=\"{}\" />\n", points.join(" ")));
+        for number in [optimum.first(), optimum.second()] {
+            svg.push_str(&format!("  <circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"3\" fill=\"red\" />\n", to_x(number), to_y(highest)));
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Finds the sum whose optimal result is the given target (the inverse of `solve_analytic`).
+    /// The optimal result scales with the cube of the sum, so this is a cube root.
+    /// Returns `None` if the target is negative (or not a number).
+    pub fn sum_for_target_result(target: f64) -> Option<f64> {
+        if target.is_nan() || target < 0.0 {
+            return None;
+        }
+        let optimum_for_unit_sum = Self::solve_analytic(1.0).result();
+        Some((target / optimum_for_unit_sum).cbrt())
+    }
+
+    /// Finds the best pairing of two non-negative integers that add up to the given sum.
+    /// Every integer split is checked, so the answer is exact (8 splits best into 2 and 6).
+    pub fn solve_integer(sum: u64) -> NumberPairing {
+        let sum_f64 = sum as f64;
+        let mut best = NumberPairing::new(0.0, sum_f64);
+        for number in 1..=(sum / 2) {
+            let pairing = NumberPairing::new(number as f64, sum_f64);
+            if pairing > best {
+                best = pairing;
+            }
+        }
+        best
+    }
+
+    /// Every pairing of two non-negative integers that add up to the given sum (smaller number first),
+    /// highest result first, e.g. to show the whole table for a small sum.
+    pub fn all_integer_pairings(sum: u64) -> Vec<NumberPairing> {
+        let sum_f64 = sum as f64;
+        let mut pairings: Vec<NumberPairing> = (0..=(sum / 2))
+            .map(|number| NumberPairing::new(number as f64, sum_f64))
+            .collect();
+        pairings.sort_by(|a, b| b.result().partial_cmp(&a.result()).unwrap_or(Ordering::Equal));
+        pairings
+    }
+}
+
+/// The progress of a problem solved with `NumberPairingProblem::solve_streaming`
+#[derive(Debug, Clone)]
+pub enum SolveEvent {
+    /// A search run finished, with the best result found so far
+    Run { count: u32, best: f64 },
+    /// The problem is solved, with these results
+    Done(Results),
+}
+
+/// The reasons a NumberPairingProblem can't be solved for the given sum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolveError {
+    /// The sum is infinite or not a number
+    NonFiniteSum,
+    /// The sum is zero or less
+    NonPositiveSum,
+    /// The sum is so large that the result is too big for an `f64`
+    ResultOverflows,
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let message = match self {
+            SolveError::NonFiniteSum => "the sum is not a finite number",
+            SolveError::NonPositiveSum => "the sum is not positive",
+            SolveError::ResultOverflows => "the sum is too large for the result to be represented",
+        };
+        write!(f, "Can't solve the number pairing problem: {}", message)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl Debug for NumberPairingProblem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        // The objective can't be shown, so only the settings and results are included
+        f.debug_struct("NumberPairingProblem")
+            .field("sum", &self.sum)
+            .field("results", &self.results)
+            .field("runs_to_solve", &self.runs_to_solve)
+            .field("collect_other_results", &self.collect_other_results)
+            .field("exact", &self.exact)
+            .field("seeded", &self.seeded)
+            .field("goal", &self.goal)
+            .field("initial_precision", &self.initial_precision)
+            .field("lower_bounds", &self.lower_bounds)
+            .field("upper_bounds", &self.upper_bounds)
+            .field("minimum_precision", &self.minimum_precision)
+            .field("result_tolerance", &self.result_tolerance)
+            .field("max_runs", &self.max_runs)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Display for NumberPairingProblem {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        let Self { sum, runs_to_solve, results: possible_results, .. } = self;
-        if let Some(results) = possible_results {
-            let Results { best, best_pairing, other } = results;
-            let mut best_list = String::new();
-            for number_pairing in best_pairing {
-                best_list.push_str(number_pairing.to_string().as_str());
-                best_list.push_str("\n");
-            }
-            let mut other_list = String::new();
-            if let Some(other_pairings) = other {
-                let max_results = if other_pairings.len() > 10 { 10 } else { other_pairings.len() };
-                for index in 0..max_results {
-                    let number_pairing = other_pairings.get(index).unwrap();
-                    other_list.push_str(number_pairing.to_string().as_str());
-                    other_list.push_str("\n");
-                }
-            }
-            let runs_str = if *runs_to_solve == 1 { "run" } else { "runs" };
-            let other_results_str = if let Some(_other_pairings) = other { format!("Other Top Results:\n{}", other_list) } else { format!("") };
-            write!(f, "\nBest Result: {} (Solved in {} {})\n\nBest Number Combination:\n{}\n{}\n", best, runs_to_solve, runs_str, best_list, other_results_str)
-        } else {
-            write!(f, "This problem (finding a number pairing summing to {}) has not yet been solved.", sum)
-        }
+        self.view().fmt(f)
     }
 }
\ No newline at end of file
@@ -0,0 +1,207 @@
+use super::{ NumberPairingProblem, DEFAULT_MARGIN_FACTOR, DEFAULT_MAX_RUNS, DEFAULT_SHRINK_FACTOR };
+use crate::defs::number_pairing::{ NumberPairing, MINIMUM_PRECISION };
+use crate::defs::objective::{ Goal, Objective, ProductTimesDifference };
+use std::sync::Arc;
+
+/// # NumberPairingProblemBuilder
+/// Configures how a NumberPairingProblem is searched before solving it.
+/// Any setting left alone uses the same default as `NumberPairingProblem::solve_with`.
+pub struct NumberPairingProblemBuilder {
+    max_runs: u32,
+    minimum_precision: f64,
+    dedup_tolerance: f64,
+    initial_precision: Option<f64>,
+    bounds: Option<(f64, f64)>,
+    margin_factor: f64,
+    shrink_factor: f64,
+    adaptive_precision: bool,
+    collect_other_results: bool,
+    exact: bool,
+    seeded: bool,
+    objective: Arc<dyn Objective>,
+    goal: Goal,
+}
+
+impl NumberPairingProblemBuilder {
+
+    // Initializers ---------------------------------------------------------- /
+
+    pub fn new() -> Self {
+        Self {
+            max_runs: DEFAULT_MAX_RUNS,
+            minimum_precision: MINIMUM_PRECISION,
+            dedup_tolerance: MINIMUM_PRECISION,
+            initial_precision: None,
+            bounds: None,
+            margin_factor: DEFAULT_MARGIN_FACTOR,
+            shrink_factor: DEFAULT_SHRINK_FACTOR,
+            adaptive_precision: false,
+            collect_other_results: true,
+            exact: false,
+            seeded: true,
+            objective: Arc::new(ProductTimesDifference),
+            goal: Goal::Maximize,
+        }
+    }
+
+    // Settings -------------------------------------------------------------- /
+
+    /// Sets the limit on recursion runs (defaults to 40)
+    pub fn max_runs(mut self, max_runs: u32) -> Self {
+        self.max_runs = max_runs;
+        self
+    }
+
+    /// Sets how close two results must be to be considered equal, which decides when the search
+    /// stops improving (defaults to `MINIMUM_PRECISION`)
+    pub fn minimum_precision(mut self, minimum_precision: f64) -> Self {
+        self.minimum_precision = minimum_precision;
+        self
+    }
+
+    /// Sets how close other results can be before the worse one is dropped as a near-duplicate
+    /// (defaults to `MINIMUM_PRECISION`). A larger tolerance gives a shorter, more varied list.
+    pub fn dedup_tolerance(mut self, dedup_tolerance: f64) -> Self {
+        self.dedup_tolerance = dedup_tolerance;
+        self
+    }
+
+    /// Sets the step size of the first search run (defaults to half the width of the bounds,
+    /// which is a quarter of the sum unless the bounds are set)
+    pub fn initial_precision(mut self, initial_precision: f64) -> Self {
+        self.initial_precision = Some(initial_precision);
+        self
+    }
+
+    /// Sets the range searched for the first number (defaults to zero through half the sum)
+    pub fn bounds(mut self, lower: f64, upper: f64) -> Self {
+        self.bounds = Some((lower, upper));
+        self
+    }
+
+    /// Sets how far around the best number so far each run searches, as a multiple of the last run's
+    /// step size (defaults to 0.5). A wider margin is less likely to miss the optimum, but takes longer.
+    pub fn margin_factor(mut self, margin_factor: f64) -> Self {
+        self.margin_factor = margin_factor;
+        self
+    }
+
+    /// Sets how quickly the step size shrinks: each run's is the last one divided by this times the
+    /// run count (defaults to 4). A smaller factor refines more gently, over more runs.
+    pub fn shrink_factor(mut self, shrink_factor: f64) -> Self {
+        self.shrink_factor = shrink_factor;
+        self
+    }
+
+    /// Sets whether the step size shrinks by more (or less) than the shrink factor between runs, depending on
+    /// how much the last run improved the best result (defaults to false). Small improvements mean the search
+    /// is close to the optimum, so it can narrow in faster, in fewer runs.
+    pub fn adaptive_precision(mut self, adaptive_precision: bool) -> Self {
+        self.adaptive_precision = adaptive_precision;
+        self
+    }
+
+    /// Sets whether other (non-best) results are collected (defaults to true)
+    pub fn collect_other_results(mut self, collect_other_results: bool) -> Self {
+        self.collect_other_results = collect_other_results;
+        self
+    }
+
+    /// Sets whether to use the objective's analytic optimum, if it has one (defaults to false)
+    pub fn exact(mut self, exact: bool) -> Self {
+        self.exact = exact;
+        self
+    }
+
+    /// Sets whether the first search run is aligned to the objective's known optimum, if it has one,
+    /// so the search converges in far fewer runs (defaults to true). Turn it off to watch the search
+    /// narrow in on the optimum over many runs (e.g. to study or trace the search itself).
+    pub fn seeded(mut self, seeded: bool) -> Self {
+        self.seeded = seeded;
+        self
+    }
+
+    /// Sets the value to optimize (defaults to the product multiplied by the difference)
+    pub fn objective<O: Objective + 'static>(mut self, objective: O) -> Self {
+        self.objective = Arc::new(objective);
+        self
+    }
+
+    /// Sets whether the objective is maximized or minimized (defaults to maximized)
+    pub fn goal(mut self, goal: Goal) -> Self {
+        self.goal = goal;
+        self
+    }
+
+    // Methods --------------------------------------------------------------- /
+
+    /// Solves the problem with given sum, using the configured settings.
+    pub fn solve(self, sum: f64) -> NumberPairingProblem {
+        let mut problem = self.build(sum);
+        problem.solve();
+        problem
+    }
+
+    /// Sets up the problem with given sum, using the configured settings, without solving it.
+    pub(super) fn build(self, sum: f64) -> NumberPairingProblem {
+        let Self {
+            max_runs,
+            minimum_precision,
+            dedup_tolerance,
+            initial_precision,
+            bounds,
+            margin_factor,
+            shrink_factor,
+            adaptive_precision,
+            collect_other_results,
+            exact,
+            seeded,
+            objective,
+            goal,
+        } = self;
+        let initial_high_value = NumberPairing::new(0.0, sum);
+        // Scaling the problem to a sum of 1 would scale every result by the same factor,
+        // so scaling the tolerance the other way is the same as solving it there
+        let result_scale = objective.scaling_exponent().map_or(1.0, |exponent| sum.abs().powi(exponent));
+        let result_tolerance = if result_scale.is_finite() && result_scale > 0.0 { minimum_precision * result_scale } else { minimum_precision };
+        let (lower_bounds, upper_bounds) = bounds.unwrap_or((0.0, sum / 2.0));
+        let initial_precision = initial_precision.unwrap_or((upper_bounds - lower_bounds) / 2.0);
+        NumberPairingProblem {
+            sum,
+            runs_to_solve: 0,
+            results: None,
+            collect_other_results,
+            exact,
+            seeded,
+            objective,
+            goal,
+            initial_high_value,
+            initial_precision,
+            lower_bounds,
+            upper_bounds,
+            overall_best_result: initial_high_value,
+            best_results: Vec::new(),
+            other_results: if collect_other_results { Some(Vec::new()) } else { None },
+            seq_best_buffer: Vec::new(),
+            seq_other_buffer: Vec::new(),
+            trace: None,
+            final_precision: 0.0,
+            last_improvement: 0.0,
+            best_found_precision: initial_precision,
+            margin_factor,
+            shrink_factor,
+            adaptive_precision,
+            minimum_precision,
+            result_tolerance,
+            dedup_tolerance,
+            run_count: 0,
+            max_runs,
+        }
+    }
+}
+
+impl Default for NumberPairingProblemBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
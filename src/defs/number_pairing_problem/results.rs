@@ -0,0 +1,261 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Error;
+use std::iter::Chain;
+use std::slice::Iter;
+use std::sync::Arc;
+use super::{ DEFAULT_DISPLAY_LIMIT, DEFAULT_DISPLAY_PRECISION };
+use crate::defs::problem_view::SortOrder;
+use crate::defs::number_pairing::NumberPairing;
+use crate::defs::objective::{ Goal, Objective };
+
+/// Stores the results of a number pairing problem
+#[derive(Clone)]
+pub struct Results {
+    pub(super) best: f64,
+    pub(super) best_pairing: Vec<NumberPairing>,
+    pub(super) other: Option<Vec<NumberPairing>>,
+    pub(super) final_precision: f64,
+    pub(super) last_improvement: f64,
+    // The objective, goal and result tolerance of the solve, so results are ranked the same way the best was chosen
+    pub(super) objective: Arc<dyn Objective>,
+    pub(super) goal: Goal,
+    pub(super) result_tolerance: f64,
+}
+
+impl Results {
+    /// The best value found
+    pub fn best(&self) -> f64 { self.best }
+
+    /// Every pairing that produced the best value
+    pub fn best_pairing(&self) -> &[NumberPairing] { &self.best_pairing }
+
+    /// The other pairings evaluated, best first (if they were collected)
+    pub fn other_results(&self) -> Option<&[NumberPairing]> { self.other.as_deref() }
+
+    /// The step size of the last search run (or the final range width for golden-section search)
+    pub fn final_precision(&self) -> f64 { self.final_precision }
+
+    /// How much the last search run improved on the best value before it (zero if it didn't).
+    /// A tiny improvement means the search converged, while a large one suggests it was cut off.
+    pub fn last_improvement(&self) -> f64 { self.last_improvement }
+
+    /// How far the (first) best pairing's numbers are from the center of the sum, if there is a best pairing
+    pub fn optimal_deviation(&self) -> Option<f64> { self.best_pairing.first().map(NumberPairing::deviation) }
+
+    /// Iterates over the best pairings, then any other results (best first)
+    pub fn iter(&self) -> Chain<Iter<'_, NumberPairing>, Iter<'_, NumberPairing>> {
+        let other = self.other.as_deref().unwrap_or_default();
+        self.best_pairing.iter().chain(other)
+    }
+
+    /// The value of the best other result that's distinctly worse than the best (by more than the solve's result tolerance),
+    /// if other results were collected and there is one
+    pub fn second_best(&self) -> Option<f64> {
+        self.other.as_deref()?
+            .iter()
+            .filter(|pairing| (self.best - self.objective.evaluate(pairing)).abs() > self.result_tolerance)
+            .min_by(|a, b| self.rank(a, b))
+            .map(|pairing| self.objective.evaluate(pairing))
+    }
+
+    /// The (up to) k best results overall, whether best or other, best first (ranked by the solve's objective and goal).
+    /// Pairings with equivalent results (such as mirrors) only appear once.
+    pub fn top_k(&self, k: usize) -> Vec<NumberPairing> {
+        let mut all: Vec<NumberPairing> = self.iter().copied().collect();
+        all.sort_by(|a, b| self.rank(a, b));
+        all.dedup_by(|later, kept| self.is_tied(later, kept));
+        all.truncate(k);
+        all
+    }
+
+    /// Counts the results of the best pairings and any other results in the given number of equal-width
+    /// buckets, spanning the lowest to the highest result, as `(bucket lower bound, count)`.
+    /// If every result is the same, they're all counted in a single bucket.
+    pub fn result_histogram(&self, buckets: usize) -> Vec<(f64, usize)> {
+        let (lowest, highest) = self.iter()
+            .map(|pairing| pairing.result())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), result| (low.min(result), high.max(result)));
+        if buckets == 0 || lowest > highest {
+            return Vec::new();
+        }
+        let width = (highest - lowest) / buckets as f64;
+        if width <= 0.0 {
+            return vec![(lowest, self.iter().count())];
+        }
+        let mut histogram: Vec<(f64, usize)> = (0..buckets).map(|index| (lowest + index as f64 * width, 0)).collect();
+        for pairing in self {
+            // The highest result would land just past the last bucket, so it's counted in the last one
+            let index = (((pairing.result() - lowest) / width) as usize).min(buckets - 1);
+            histogram[index].1 += 1;
+        }
+        histogram
+    }
+
+    /// Writes the best pairings, then any other results, as CSV rows under a header.
+    /// Values are written at full precision, rather than rounded as in `Display`.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("first,second,product,difference,result\n");
+        for pairing in self {
+            csv.push_str(&format!("{},{},{},{},{}\n", pairing.first(), pairing.second(), pairing.product(), pairing.difference(), pairing.result()));
+        }
+        csv
+    }
+
+    /// Combines these results with another set for the same sum (e.g. from a solve at a different precision),
+    /// re-ranking every pairing by this solve's objective and goal. Those equivalent to the best become the best pairings,
+    /// and the rest the other results (if either set collected them), with near-equal duplicates removed.
+    /// The final precision and last improvement are taken from whichever set searched more finely.
+    /// Results with a different goal, or whose objective values the other's best pairing differently, can't be merged.
+    pub fn merge(&mut self, other: Results) -> Result<(), MergeError> {
+        let self_sum = self.best_pairing.first().map(|pairing| pairing.sum);
+        let other_sum = other.best_pairing.first().map(|pairing| pairing.sum);
+        if let (Some(self_sum), Some(other_sum)) = (self_sum, other_sum) {
+            if self_sum != other_sum {
+                return Err(MergeError::DifferentSums);
+            }
+        }
+        if self.goal != other.goal {
+            return Err(MergeError::DifferentGoals);
+        }
+        if let Some(pairing) = other.best_pairing.first() {
+            if self.objective.evaluate(pairing) != other.objective.evaluate(pairing) {
+                return Err(MergeError::DifferentObjectives);
+            }
+        }
+        let mut all: Vec<NumberPairing> = self.iter().chain(other.iter()).copied().collect();
+        all.sort_by(|a, b| self.rank(a, b));
+        let top = match all.first() {
+            Some(top) => *top,
+            None => return Ok(()),
+        };
+        let mut best_pairing: Vec<NumberPairing> = Vec::new();
+        let mut rest: Vec<NumberPairing> = Vec::new();
+        for pairing in all {
+            if self.is_tied(&pairing, &top) {
+                // A pairing and its mirror are equal (allowing for rounding error), so only keep the first of each
+                if !best_pairing.iter().any(|kept| kept.approx_eq(&pairing, NumberPairing::minimum_precision())) {
+                    best_pairing.push(pairing);
+                }
+            } else {
+                rest.push(pairing);
+            }
+        }
+        rest.dedup_by(|later, kept| self.is_tied(later, kept));
+        if other.final_precision < self.final_precision {
+            self.final_precision = other.final_precision;
+            self.last_improvement = other.last_improvement;
+        }
+        self.best = self.objective.evaluate(&top);
+        self.best_pairing = best_pairing;
+        self.other = if self.other.is_some() || other.other.is_some() { Some(rest) } else { None };
+        Ok(())
+    }
+
+    /// Writes the best pairings, then (if collected) at most the given number of the top other results,
+    /// in the given order, with numbers rounded to the given decimal places
+    pub(crate) fn write_pairings(&self, f: &mut Formatter<'_>, display_limit: usize, sort_order: SortOrder, precision: usize) -> Result<(), Error> {
+        let mut best_list = String::new();
+        for number_pairing in self.best_pairing() {
+            best_list.push_str(number_pairing.format(precision, false).as_str());
+            best_list.push('\n');
+        }
+        let mut other_list = String::new();
+        if let Some(other_pairings) = self.other_results() {
+            let top_pairings = &other_pairings[..display_limit.min(other_pairings.len())];
+            let ordered: Box<dyn Iterator<Item = &NumberPairing>> = match sort_order {
+                SortOrder::Descending => Box::new(top_pairings.iter()),
+                SortOrder::Ascending => Box::new(top_pairings.iter().rev()),
+            };
+            for number_pairing in ordered {
+                other_list.push_str(number_pairing.format(precision, false).as_str());
+                other_list.push('\n');
+            }
+        }
+        let other_results_str = if self.other_results().is_some() { format!("Other Top Results:\n{}", other_list) } else { String::new() };
+        write!(f, "Best Number Combination:\n{}\n{}\n", best_list, other_results_str)
+    }
+
+    /// Compares two sets of results, allowing for float differences (e.g. between search methods).
+    /// They match if their best values are within the tolerance, and every best pairing in one has
+    /// a best pairing in the other whose numbers are within the tolerance (in either order).
+    pub fn approx_eq(&self, other: &Results, tolerance: f64) -> bool {
+        let is_match = |left: &NumberPairing, right: &NumberPairing| -> bool {
+            left.sum == right.sum
+                && ((left.first() - right.first()).abs() <= tolerance || (left.first() - right.second()).abs() <= tolerance)
+        };
+        let all_matched = |from: &[NumberPairing], to: &[NumberPairing]| -> bool {
+            from.iter().all(|left| to.iter().any(|right| is_match(left, right)))
+        };
+        (self.best - other.best).abs() <= tolerance
+            && all_matched(&self.best_pairing, &other.best_pairing)
+            && all_matched(&other.best_pairing, &self.best_pairing)
+    }
+
+    // Private Methods ------------------------------------------------------- /
+
+    /// Orders pairings best first, by the solve's objective and goal
+    fn rank(&self, a: &NumberPairing, b: &NumberPairing) -> Ordering {
+        self.goal.orient(b.cmp_by(a, self.objective.as_ref()))
+    }
+
+    /// Tests if two pairings' values are within the solve's result tolerance of each other
+    fn is_tied(&self, a: &NumberPairing, b: &NumberPairing) -> bool {
+        self.objective.is_equivalent_within(a, b, self.result_tolerance)
+    }
+}
+
+impl<'a> IntoIterator for &'a Results {
+    type Item = &'a NumberPairing;
+    type IntoIter = Chain<Iter<'a, NumberPairing>, Iter<'a, NumberPairing>>;
+
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl Debug for Results {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        // The objective can't be shown, so only the values and the goal are included
+        f.debug_struct("Results")
+            .field("best", &self.best)
+            .field("best_pairing", &self.best_pairing)
+            .field("other", &self.other)
+            .field("final_precision", &self.final_precision)
+            .field("last_improvement", &self.last_improvement)
+            .field("goal", &self.goal)
+            .field("result_tolerance", &self.result_tolerance)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Display for Results {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "\nBest Result: {}\n\n", self.best)?;
+        self.write_pairings(f, DEFAULT_DISPLAY_LIMIT, SortOrder::Descending, DEFAULT_DISPLAY_PRECISION)
+    }
+}
+
+/// The reasons two sets of results can't be merged
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeError {
+    /// The results are for pairings with different sums
+    DifferentSums,
+    /// One set of results was maximized and the other minimized
+    DifferentGoals,
+    /// The results were ranked by different objectives
+    DifferentObjectives,
+}
+
+impl Display for MergeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let message = match self {
+            MergeError::DifferentSums => "the results are for different sums",
+            MergeError::DifferentGoals => "the results have different goals",
+            MergeError::DifferentObjectives => "the results have different objectives",
+        };
+        write!(f, "Can't merge results: {}", message)
+    }
+}
+
+impl std::error::Error for MergeError {}
@@ -0,0 +1,178 @@
+use super::number_pairing::{ NumberPairing, MINIMUM_PRECISION };
+#[cfg(feature = "std")]
+use super::number_pairing_problem::NumberPairingProblem;
+use core::cmp::Ordering;
+use crate::math;
+
+/// A value to maximize when searching for the best NumberPairing.
+/// Any closure taking a `&NumberPairing` and returning an `f64` can be used as an objective.
+/// Objectives must be thread-safe, so solved problems can be sent between threads.
+pub trait Objective: Send + Sync {
+    /// Evaluates a pairing. Higher values are better.
+    fn evaluate(&self, pairing: &NumberPairing) -> f64;
+
+    /// Compares two pairings by their evaluated values
+    fn compare(&self, left: &NumberPairing, right: &NumberPairing) -> Ordering {
+        let l_value = self.evaluate(left);
+        let r_value = self.evaluate(right);
+        if l_value > r_value {
+            Ordering::Greater
+        } else if l_value < r_value {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// This will test if two evaluated values are close enough to be considered equal to each other
+    fn is_equivalent(&self, left: &NumberPairing, right: &NumberPairing) -> bool {
+        self.is_equivalent_within(left, right, MINIMUM_PRECISION)
+    }
+
+    /// This will test if two evaluated values are within the given tolerance of each other
+    fn is_equivalent_within(&self, left: &NumberPairing, right: &NumberPairing, tolerance: f64) -> bool {
+        (self.evaluate(left) - self.evaluate(right)).abs() < tolerance
+    }
+
+    /// The exact optimal pairing for the given sum, if it is known without searching
+    fn analytic_optimum(&self, _sum: f64) -> Option<NumberPairing> { None }
+
+    /// The power n, if scaling both numbers (and so the sum) by k always scales the value by kⁿ.
+    /// Knowing this, the search compares values as if the problem were scaled to a sum of 1,
+    /// so very small and very large sums are searched as precisely as moderate ones.
+    fn scaling_exponent(&self) -> Option<i32> { None }
+
+    /// Checks whether the objective rises to a single peak (or valley) and no more, by evaluating evenly spaced
+    /// samples of the first number across `[0, sum/2]` (the range searched, as the rest mirrors it).
+    /// The search assumes this, so for an objective that fails the check it may only find a local optimum.
+    fn verify_unimodal(&self, sum: f64, samples: usize) -> bool {
+        if samples < 3 {
+            return true;
+        }
+        let step = sum / 2.0 / (samples - 1) as f64;
+        let mut previous_value = self.evaluate(&NumberPairing::new(0.0, sum));
+        let mut previous_sign = Ordering::Equal;
+        let mut sign_changes = 0;
+        for index in 1..samples {
+            let value = self.evaluate(&NumberPairing::new(step * index as f64, sum));
+            let sign = value.partial_cmp(&previous_value).unwrap_or(Ordering::Equal);
+            if sign != Ordering::Equal {
+                if previous_sign != Ordering::Equal && sign != previous_sign {
+                    sign_changes += 1;
+                }
+                previous_sign = sign;
+            }
+            previous_value = value;
+        }
+        sign_changes <= 1
+    }
+}
+
+/// The objective of the original problem: the product multiplied by the difference
+pub struct ProductTimesDifference;
+
+impl Objective for ProductTimesDifference {
+    fn evaluate(&self, pairing: &NumberPairing) -> f64 { pairing.result() }
+
+    /// The product scales with the square of the sum and the difference with the sum
+    fn scaling_exponent(&self) -> Option<i32> { Some(3) }
+
+    #[cfg(feature = "std")]
+    fn analytic_optimum(&self, sum: f64) -> Option<NumberPairing> {
+        Some(NumberPairingProblem::solve_analytic(sum))
+    }
+}
+
+/// A variation on the original problem, raising the product and the difference to integer powers
+/// (e.g. the product squared times the difference). The default powers of one give the original problem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoweredProductTimesDifference {
+    pub product_exponent: i32,
+    pub difference_exponent: i32,
+}
+
+impl PoweredProductTimesDifference {
+    pub fn new(product_exponent: i32, difference_exponent: i32) -> Self {
+        Self { product_exponent, difference_exponent }
+    }
+}
+
+impl Default for PoweredProductTimesDifference {
+    fn default() -> Self {
+        Self::new(1, 1)
+    }
+}
+
+impl Objective for PoweredProductTimesDifference {
+    fn evaluate(&self, pairing: &NumberPairing) -> f64 {
+        math::powi(pairing.product(), self.product_exponent) * math::powi(pairing.difference(), self.difference_exponent)
+    }
+
+    /// With product `S²/4 − t²` and difference `2t` (for a deviation `t` from the center of the sum),
+    /// the optimum is at `t = (S/2)·√(q / (q + 2p))`, for positive powers `p` and `q`
+    fn analytic_optimum(&self, sum: f64) -> Option<NumberPairing> {
+        let Self { product_exponent, difference_exponent } = *self;
+        if product_exponent <= 0 || difference_exponent <= 0 {
+            return None;
+        }
+        let (p, q) = (product_exponent as f64, difference_exponent as f64);
+        Some(NumberPairing::from_deviation(sum, sum / 2.0 * math::sqrt(q / (q + 2.0 * p))))
+    }
+
+    fn scaling_exponent(&self) -> Option<i32> {
+        Some(2 * self.product_exponent + self.difference_exponent)
+    }
+}
+
+/// A variation on the original problem, adding the product and the difference with weights,
+/// rather than multiplying them (see `NumberPairing::weighted_result`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeightedProductPlusDifference {
+    pub product_weight: f64,
+    pub difference_weight: f64,
+}
+
+impl WeightedProductPlusDifference {
+    pub fn new(product_weight: f64, difference_weight: f64) -> Self {
+        Self { product_weight, difference_weight }
+    }
+}
+
+impl Objective for WeightedProductPlusDifference {
+    fn evaluate(&self, pairing: &NumberPairing) -> f64 {
+        pairing.weighted_result(self.product_weight, self.difference_weight)
+    }
+
+    /// With product `S²/4 − t²` and difference `2t` (for a deviation `t` from the center of the sum),
+    /// the optimum is at `t = w_d / w_p` (up to half the sum), for a positive product weight `w_p`
+    fn analytic_optimum(&self, sum: f64) -> Option<NumberPairing> {
+        if self.product_weight <= 0.0 {
+            return None;
+        }
+        Some(NumberPairing::from_deviation(sum, (self.difference_weight / self.product_weight).max(0.0)))
+    }
+}
+
+impl<F> Objective for F where F: Fn(&NumberPairing) -> f64 + Send + Sync {
+    fn evaluate(&self, pairing: &NumberPairing) -> f64 { self(pairing) }
+}
+
+/// Whether the search looks for the pairing with the highest or the lowest objective value.
+/// For the default objective the minimum is degenerate (zero, at the endpoints), so minimizing
+/// is really only meaningful for alternative objectives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Goal {
+    #[default]
+    Maximize,
+    Minimize,
+}
+
+impl Goal {
+    /// Orients an objective comparison so that `Ordering::Greater` always means "better" for this goal
+    pub fn orient(&self, ordering: Ordering) -> Ordering {
+        match self {
+            Goal::Maximize => ordering,
+            Goal::Minimize => ordering.reverse(),
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use super::number_pairing::NumberPairing;
+use crate::util::format_float;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::result::Result;
+use std::fmt::Error;
+
+/// A split of a sum into any number of non-negative parts.
+/// Finds the product of all parts, the spread (largest minus smallest part) and the result of multiplying them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Partition {
+    parts: Vec<f64>,
+}
+
+impl Partition {
+    pub fn parts(&self) -> &[f64] { &self.parts }
+    pub fn sum(&self) -> f64 { self.parts.iter().sum() }
+    pub fn product(&self) -> f64 { self.parts.iter().product() }
+    pub fn spread(&self) -> f64 { Self::spread_of(&self.parts) }
+    pub fn result(&self) -> f64 { Self::evaluate(&self.parts) }
+
+    // Initializers ---------------------------------------------------------- /
+
+    /// Creates a partition from the given parts, making any negative part positive
+    pub fn new(parts: Vec<f64>) -> Self {
+        Self { parts: parts.into_iter().map(f64::abs).collect() }
+    }
+
+    // Static ---------------------------------------------------------------- /
+
+    /// The product of all parts multiplied by the spread
+    fn evaluate(parts: &[f64]) -> f64 {
+        parts.iter().product::<f64>() * Self::spread_of(parts)
+    }
+
+    /// The largest part minus the smallest part (zero when there are no parts)
+    fn spread_of(parts: &[f64]) -> f64 {
+        let max = parts.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let min = parts.iter().cloned().fold(f64::INFINITY, f64::min);
+        if parts.is_empty() { 0.0 } else { max - min }
+    }
+}
+
+impl Display for Partition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let precision = 4;
+        let parts: Vec<String> = self.parts.iter().map(|part| format_float(part, &precision)).collect();
+        let sum = format_float(&self.sum(), &precision);
+        let spread = format_float(&self.spread(), &precision);
+        let product = format_float(&self.product(), &precision);
+        let result = format_float(&self.result(), &precision);
+        write!(f, "{} -> {} (spread: {}, product: {} -> result: {})", parts.join(", "), sum, spread, product, result)
+    }
+}
+
+/// # PartitionProblem
+/// Generalizes NumberPairingProblem from two numbers to `parts` numbers: split the sum
+/// into non-negative parts, such that the product of all parts multiplied by the spread
+/// (largest minus smallest part) produces the largest possible value.
+/// With 2 parts, this is the same problem as NumberPairingProblem.
+pub struct PartitionProblem {
+    pub sum: f64,
+    pub parts: usize,
+}
+
+impl PartitionProblem {
+
+    // Initializers ---------------------------------------------------------- /
+
+    pub fn new(sum: f64, parts: usize) -> Self {
+        Self { sum, parts }
+    }
+
+    // Methods --------------------------------------------------------------- /
+
+    /// Solves the problem by coordinate descent: each pass goes through every pair of parts and
+    /// finds the best split of their combined amount (holding the other parts fixed), until a
+    /// pass no longer improves the result.
+    ///
+    /// Each pass costs O(n³) evaluation work for n parts, and the passes needed grow with n,
+    /// so this is practical for a handful of parts (up to around 10). Since the problem is
+    /// multimodal for more than 2 parts, the answer may be a local rather than global optimum.
+    pub fn solve(&self) -> Partition {
+        let Self { sum, parts } = *self;
+        if parts < 2 {
+            // With fewer than 2 parts, there's nothing to split
+            return Partition::new(if parts == 1 { vec![sum] } else { Vec::new() });
+        }
+
+        // Start from an uneven ramp, since equal parts have no spread to improve on
+        let ramp_total = (parts * (parts + 1) / 2) as f64;
+        let mut values: Vec<f64> = (1..=parts).map(|step| sum * step as f64 / ramp_total).collect();
+        let mut best = Partition::evaluate(&values);
+
+        let max_passes = 200;
+        for _ in 0..max_passes {
+            let previous_best = best;
+            for i in 0..parts {
+                for j in (i + 1)..parts {
+                    let pair_total = values[i] + values[j];
+                    let split = Self::best_split(pair_total, |amount| {
+                        let mut candidate = values.clone();
+                        candidate[i] = amount;
+                        candidate[j] = pair_total - amount;
+                        Partition::evaluate(&candidate)
+                    });
+                    values[i] = split;
+                    values[j] = pair_total - split;
+                    best = Partition::evaluate(&values);
+                }
+            }
+            if best - previous_best < NumberPairing::minimum_precision() {
+                break;
+            }
+        }
+        Partition::new(values)
+    }
+
+    /// Solves the problem exactly on a grid, where every part is a whole number of steps (of the sum
+    /// divided by `steps`), so the answer is the global optimum to within a step.
+    ///
+    /// The search assigns the parts smallest first (since their order doesn't change the result), leaving
+    /// the last part to make up the sum, and skips any partial assignment that can't beat the best so far:
+    /// the rest of the parts can at most multiply to what they would if they were equal, and the spread
+    /// can at most reach the largest the last part could be, less the smallest part.
+    /// Naive enumeration is O(s^(n−1)) for s steps and n parts; keeping the parts in order cuts this
+    /// by about n!, and pruning cuts it further, so it's practical for moderate n (and a few hundred steps).
+    pub fn solve_pruned(&self, steps: usize) -> Partition {
+        let Self { sum, parts } = *self;
+        if parts < 2 {
+            // With fewer than 2 parts, there's nothing to split
+            return Partition::new(if parts == 1 { vec![sum] } else { Vec::new() });
+        }
+        let steps = steps.max(1);
+        let step = sum / steps as f64;
+        let mut best: (f64, Vec<usize>) = (f64::NEG_INFINITY, Vec::new());
+        Self::search_pruned(&mut Vec::with_capacity(parts), steps, parts, step, &mut best);
+        Partition::new(best.1.iter().map(|&units| units as f64 * step).collect())
+    }
+
+    // Static ---------------------------------------------------------------- /
+
+    /// Assigns the rest of the parts (in whole steps, each at least the last one assigned) out of the
+    /// remaining steps, recording the best full assignment found
+    fn search_pruned(assigned: &mut Vec<usize>, remaining: usize, parts: usize, step: f64, best: &mut (f64, Vec<usize>)) {
+        let to_assign = parts - assigned.len();
+        if to_assign == 1 {
+            // The last part is whatever's left, which is the largest, as the parts are in order
+            assigned.push(remaining);
+            let value = Partition::evaluate(&assigned.iter().map(|&units| units as f64 * step).collect::<Vec<f64>>());
+            if value > best.0 {
+                *best = (value, assigned.clone());
+            }
+            assigned.pop();
+            return;
+        }
+        let previous = assigned.last().copied().unwrap_or(0);
+        let smallest = assigned.first().copied().unwrap_or(0) as f64 * step;
+        let assigned_product: f64 = assigned.iter().map(|&units| units as f64 * step).product();
+        let remaining_amount = remaining as f64 * step;
+        let largest = (remaining - (to_assign - 1) * previous) as f64 * step;
+        let bound = assigned_product * (remaining_amount / to_assign as f64).powi(to_assign as i32) * (largest - smallest);
+        if bound <= best.0 {
+            return;
+        }
+        // Each part leaves enough steps for the parts after it to be at least as large
+        for units in previous..=(remaining / to_assign) {
+            assigned.push(units);
+            Self::search_pruned(assigned, remaining - units, parts, step, best);
+            assigned.pop();
+        }
+    }
+
+    /// Finds the amount (between 0 and the total) that maximizes the given function.
+    /// Like NumberPairingProblem's search, this sweeps the range, then sweeps again more
+    /// finely around the best amount found, so it copes with more than one peak.
+    fn best_split<F: Fn(f64) -> f64>(total: f64, evaluate: F) -> f64 {
+        let steps = 20;
+        let mut low = 0.0;
+        let mut high = total;
+        let mut best_amount = 0.0;
+        let mut best_value = evaluate(best_amount);
+        while high - low > total * NumberPairing::minimum_precision() {
+            let step = (high - low) / steps as f64;
+            for index in 0..=steps {
+                let amount = low + index as f64 * step;
+                let value = evaluate(amount);
+                if value > best_value {
+                    best_amount = amount;
+                    best_value = value;
+                }
+            }
+            low = (best_amount - step).max(0.0);
+            high = (best_amount + step).min(total);
+        }
+        best_amount
+    }
+}
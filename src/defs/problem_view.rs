@@ -0,0 +1,71 @@
+use super::number_pairing_problem::{ NumberPairingProblem, DEFAULT_DISPLAY_LIMIT, DEFAULT_DISPLAY_PRECISION };
+use crate::util::format_float;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::result::Result;
+use std::fmt::Error;
+
+/// The order other (non-best) results are shown in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    /// Highest result first
+    #[default]
+    Descending,
+    /// Lowest result first
+    Ascending,
+}
+
+/// A displayable view of a NumberPairingProblem, with control over how much is shown.
+pub struct ProblemView<'a> {
+    problem: &'a NumberPairingProblem,
+    display_limit: usize,
+    sort_order: SortOrder,
+    precision: usize,
+}
+
+impl<'a> ProblemView<'a> {
+
+    // Initializers ---------------------------------------------------------- /
+
+    /// Creates a view showing at most the default of 10 other results, with numbers to 4 decimal places
+    pub fn new(problem: &'a NumberPairingProblem) -> Self {
+        Self { problem, display_limit: DEFAULT_DISPLAY_LIMIT, sort_order: SortOrder::Descending, precision: DEFAULT_DISPLAY_PRECISION }
+    }
+
+    // Settings -------------------------------------------------------------- /
+
+    /// Sets the most other (non-best) results shown
+    pub fn display_limit(mut self, display_limit: usize) -> Self {
+        self.display_limit = display_limit;
+        self
+    }
+
+    /// Sets the order the other results are shown in (defaults to descending).
+    /// Either way, the top results are shown, up to the display limit.
+    pub fn sort_order(mut self, sort_order: SortOrder) -> Self {
+        self.sort_order = sort_order;
+        self
+    }
+
+    /// Sets the decimal places the numbers of each pairing are shown to
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+}
+
+impl<'a> Display for ProblemView<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let Self { problem, display_limit, sort_order, precision } = self;
+        let NumberPairingProblem { sum, .. } = problem;
+        let runs_to_solve = problem.runs_to_solve();
+        if let Some(results) = problem.results() {
+            let runs_str = if runs_to_solve == 1 { "run" } else { "runs" };
+            let best = format_float(&results.best(), precision);
+            write!(f, "\nBest Result: {} (Solved in {} {})\n\n", best, runs_to_solve, runs_str)?;
+            results.write_pairings(f, *display_limit, *sort_order, *precision)
+        } else {
+            write!(f, "This problem (finding a number pairing summing to {}) has not yet been solved.", sum)
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use super::number_pairing_problem::NumberPairingProblem;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::result::Result;
+use std::fmt::Error;
+
+/// A displayable report of how close a solved NumberPairingProblem got to the analytic optimum.
+/// The analytic optimum is for the default objective (maximizing the product times the difference).
+pub struct SolveReport<'a> {
+    problem: &'a NumberPairingProblem,
+}
+
+impl<'a> SolveReport<'a> {
+
+    // Initializers ---------------------------------------------------------- /
+
+    pub fn new(problem: &'a NumberPairingProblem) -> Self {
+        Self { problem }
+    }
+}
+
+impl<'a> Display for SolveReport<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let NumberPairingProblem { sum, .. } = self.problem;
+        let runs_to_solve = self.problem.runs_to_solve();
+        if let Some(results) = self.problem.results() {
+            let analytic = NumberPairingProblem::solve_analytic(*sum).result();
+            let runs_str = if runs_to_solve == 1 { "run" } else { "runs" };
+            writeln!(f, "Numeric Best: {}", results.best())?;
+            writeln!(f, "Analytic Optimum: {}", analytic)?;
+            writeln!(f, "Discrepancy: {:e}", (results.best() - analytic).abs())?;
+            writeln!(f, "Solved in {} {}", runs_to_solve, runs_str)
+        } else {
+            write!(f, "This problem (finding a number pairing summing to {}) has not yet been solved.", sum)
+        }
+    }
+}
@@ -0,0 +1,27 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod defs;
+mod math;
+#[cfg(feature = "std")]
+pub mod util;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "decimal")]
+pub use crate::defs::decimal_pairing::DecimalPairing;
+#[cfg(feature = "std")]
+pub use crate::defs::dto::{ PairingDto, ProblemDto };
+#[cfg(feature = "std")]
+pub use crate::defs::fixed_product_problem::FixedProductProblem;
+pub use crate::defs::number_pairing::{ NumberPairing, PairingError, cmp_by_deviation, MINIMUM_PRECISION };
+#[cfg(feature = "std")]
+pub use crate::defs::number_pairing_problem::{ NumberPairingProblem, NumberPairingProblemBuilder, MergeError, Results, SolveError, SolveEvent, DEFAULT_DISPLAY_PRECISION, DEFAULT_MARGIN_FACTOR, DEFAULT_MAX_RUNS, DEFAULT_SHRINK_FACTOR };
+pub use crate::defs::objective::{ Goal, Objective, PoweredProductTimesDifference, ProductTimesDifference, WeightedProductPlusDifference };
+#[cfg(feature = "std")]
+pub use crate::defs::partition_problem::{ Partition, PartitionProblem };
+#[cfg(feature = "std")]
+pub use crate::defs::problem_view::{ ProblemView, SortOrder };
+#[cfg(feature = "std")]
+pub use crate::defs::solve_report::SolveReport;
+#[cfg(feature = "std")]
+pub use crate::util::{ make_line, make_line_with, format_float, format_problem_table };
\ No newline at end of file
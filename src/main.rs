@@ -1,13 +1,185 @@
-#![allow(dead_code)]
+use highest_number_pairings::{ NumberPairing, NumberPairingProblem, ProblemDto, DEFAULT_DISPLAY_PRECISION, DEFAULT_MAX_RUNS, make_line_with, format_float };
+use std::env;
+use std::io::{ self, BufRead };
+use std::process;
 
-mod defs;
-mod util;
+/// The most decimal places `--precision` allows, beyond which an f64 has no more meaningful digits
+const MAX_PRECISION: usize = 17;
 
-use crate::defs::number_pairing_problem::NumberPairingProblem;
-use crate::util::{ make_line, format_float };
+/// How the solved problem is written to stdout
+enum OutputFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+/// Options read from the command-line arguments
+struct Options {
+    sum: f64,
+    collect_other_results: bool,
+    format: OutputFormat,
+    from_stdin: bool,
+    explain: bool,
+    max_runs: u32,
+    border: char,
+    precision: usize,
+}
 
 fn main() {
-    let number_pairing_problem = NumberPairingProblem::solve_with(8.0, true);
-    let intro = format!("Problem:\nFind two numbers that add up to {}, such that the product multiplied by the difference produces the largest possible value.", format_float(&number_pairing_problem.sum, &0));
-    println!("\n{}\n\n{}\n{}{}\n", make_line(15), intro, number_pairing_problem, make_line(15));
+    let options = parse_args().unwrap_or_else(|message| {
+        eprintln!("Error: {}", message);
+        process::exit(1);
+    });
+    if !options.from_stdin {
+        print_solved(options.sum, &options);
+        return;
+    }
+    // Each line holds one sum, and lines that can't be read or solved are skipped with a warning
+    let mut is_first = true;
+    for (index, line) in io::stdin().lock().lines().enumerate() {
+        let line = match line {
+            Ok(line) => line,
+            Err(error) => {
+                eprintln!("Warning: couldn't read line {}: {}", index + 1, error);
+                continue;
+            }
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        match parse_sum(line.trim()) {
+            Ok(sum) => {
+                // Text blocks already start and end with a blank line, and JSON is written one object per line
+                if !is_first && matches!(options.format, OutputFormat::Csv) {
+                    println!();
+                }
+                print_solved(sum, &options);
+                is_first = false;
+            }
+            Err(message) => eprintln!("Warning: skipping line {}: {}", index + 1, message),
+        }
+    }
+}
+
+/// Solves the problem for the sum, printing it as set by the options
+fn print_solved(sum: f64, options: &Options) {
+    let number_pairing_problem = NumberPairingProblem::builder()
+        .collect_other_results(options.collect_other_results)
+        .max_runs(options.max_runs)
+        .solve(sum);
+    match options.format {
+        OutputFormat::Text => {
+            let intro = format!("Problem:\nFind two numbers that add up to {}, such that the product multiplied by the difference produces the largest possible value.", format_float(&number_pairing_problem.sum, &options.precision));
+            let derivation = if options.explain { explain(sum, options.precision) } else { String::new() };
+            let line = make_line_with(15, options.border);
+            let view = number_pairing_problem.view().precision(options.precision);
+            println!("\n{}\n\n{}\n{}{}{}\n", line, intro, view, derivation, line);
+        }
+        OutputFormat::Json => {
+            let json = serde_json::to_string(&ProblemDto::from(&number_pairing_problem)).expect("A solved problem can always be serialized");
+            println!("{}", json);
+        }
+        OutputFormat::Csv => {
+            if let Some(results) = number_pairing_problem.results() {
+                print!("{}", results.to_csv());
+            }
+        }
+    }
+}
+
+/// Describes how the optimum is found algebraically, with the numbers taken from the analytic solution
+/// and shown to the given decimal places
+fn explain(sum: f64, precision: usize) -> String {
+    let optimum = NumberPairing::optimal_for(sum);
+    let (low, high) = optimum.as_sorted();
+    let show = |number: f64| format_float(&number, &precision);
+    let mut derivation = String::from("Derivation:\n");
+    derivation.push_str(&format!("Write the numbers as a = S/2 + t and b = S/2 - t, so they add up to S = {}.\n", show(sum)));
+    derivation.push_str("The product is a·b = S²/4 - t² and the difference is a - b = 2t,\n");
+    derivation.push_str("so the result is f(t) = (S²/4 - t²)·2t = S²t/2 - 2t³.\n");
+    derivation.push_str("Its derivative is f'(t) = S²/2 - 6t², which is zero where t² = S²/12,\n");
+    derivation.push_str(&format!("so t = S/(2√3) = {}.\n", show(optimum.deviation())));
+    derivation.push_str(&format!("The numbers are S/2 - t = {} and S/2 + t = {}, with a result of {}.\n\n", show(low), show(high), show(optimum.result())));
+    derivation
+}
+
+/// Reads the command-line arguments into options
+/// Usage: `[SUM | --stdin] [--no-other] [--explain] [--format text|json|csv] [--border CHAR] [--precision N]`, where the sum defaults to 8
+fn parse_args() -> Result<Options, String> {
+    let mut options = Options {
+        sum: 8.0,
+        collect_other_results: true,
+        format: OutputFormat::Text,
+        from_stdin: false,
+        explain: false,
+        max_runs: max_runs_from_env(),
+        border: '-',
+        precision: DEFAULT_DISPLAY_PRECISION,
+    };
+    let mut sum_given = false;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--no-other" => options.collect_other_results = false,
+            "--stdin" => options.from_stdin = true,
+            "--explain" => options.explain = true,
+            "--format" => {
+                options.format = match args.next().as_deref() {
+                    Some("text") => OutputFormat::Text,
+                    Some("json") => OutputFormat::Json,
+                    Some("csv") => OutputFormat::Csv,
+                    Some(other) => return Err(format!("Unknown format '{}' (expected text, json or csv)", other)),
+                    None => return Err(String::from("--format requires a value (text, json or csv)")),
+                };
+            }
+            "--border" => {
+                let value = args.next().unwrap_or_default();
+                let mut characters = value.chars();
+                options.border = match (characters.next(), characters.next()) {
+                    (Some(character), None) => character,
+                    _ => return Err(format!("--border requires a single character, but got '{}'", value)),
+                };
+            }
+            "--precision" => {
+                let value = args.next().unwrap_or_default();
+                options.precision = match value.parse::<usize>() {
+                    Ok(precision) if precision <= MAX_PRECISION => precision,
+                    _ => return Err(format!("--precision requires a whole number of decimal places from 0 to {}, but got '{}'", MAX_PRECISION, value)),
+                };
+            }
+            _ if arg.starts_with("--") => return Err(format!("Unknown argument '{}'", arg)),
+            _ if sum_given => return Err(format!("Unexpected extra argument '{}'", arg)),
+            _ => {
+                options.sum = parse_sum(&arg)?;
+                sum_given = true;
+            }
+        }
+    }
+    if options.from_stdin && sum_given {
+        return Err(String::from("A sum can't be given with --stdin, which reads the sums from stdin"));
+    }
+    Ok(options)
+}
+
+/// Reads the limit on search runs from the `HNP_MAX_RUNS` environment variable, if it's set to a
+/// positive whole number (otherwise, the default is used)
+fn max_runs_from_env() -> u32 {
+    match env::var("HNP_MAX_RUNS") {
+        Ok(value) => match value.trim().parse::<u32>() {
+            Ok(max_runs) if max_runs > 0 => max_runs,
+            _ => {
+                eprintln!("Warning: ignoring HNP_MAX_RUNS='{}', which isn't a positive whole number", value);
+                DEFAULT_MAX_RUNS
+            }
+        },
+        Err(_) => DEFAULT_MAX_RUNS,
+    }
+}
+
+/// Parses the sum, which must be a positive (finite) number
+fn parse_sum(arg: &str) -> Result<f64, String> {
+    match arg.parse::<f64>() {
+        Ok(sum) if sum.is_finite() && sum > 0.0 => Ok(sum),
+        _ => Err(format!("The sum must be a positive number, but got '{}'", arg)),
+    }
 }
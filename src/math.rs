@@ -0,0 +1,25 @@
+// Float functions that aren't available in `core`, so `no_std` builds use `libm` for them instead
+
+#[cfg(feature = "std")]
+pub fn powi(float: f64, exponent: i32) -> f64 { float.powi(exponent) }
+
+#[cfg(not(feature = "std"))]
+pub fn powi(float: f64, exponent: i32) -> f64 { libm::pow(float, exponent as f64) }
+
+#[cfg(feature = "std")]
+pub fn sqrt(float: f64) -> f64 { float.sqrt() }
+
+#[cfg(not(feature = "std"))]
+pub fn sqrt(float: f64) -> f64 { libm::sqrt(float) }
+
+#[cfg(feature = "std")]
+pub fn round(float: f64) -> f64 { float.round() }
+
+#[cfg(not(feature = "std"))]
+pub fn round(float: f64) -> f64 { libm::round(float) }
+
+#[cfg(feature = "std")]
+pub fn floor(float: f64) -> f64 { float.floor() }
+
+#[cfg(not(feature = "std"))]
+pub fn floor(float: f64) -> f64 { libm::floor(float) }
@@ -1,10 +1,55 @@
+use crate::defs::number_pairing_problem::NumberPairingProblem;
+
 /// Makes a line
 pub fn make_line(length: usize) -> String {
-    "-".repeat(length)
+    make_line_with(length, '-')
+}
+
+/// Makes a line of the given character (repeated as a whole character, so multi-byte ones work too)
+pub fn make_line_with(length: usize, character: char) -> String {
+    character.to_string().repeat(length)
 }
 
 /// Formats a floating point number as string
+/// Trailing zeros after the decimal point (and a dangling decimal point) are trimmed
 pub fn format_float(float: &f64, precision: &usize) -> String {
-    let rounded = if float % 1.0 == 0.0 { format!("{:.0}", float) } else { format!("{1:.0$}", precision, float) };
-    String::from(rounded.trim_matches(|a| a == '0'))
-}
\ No newline at end of file
+    let rounded = format!("{1:.0$}", precision, float);
+    if rounded.contains('.') {
+        String::from(rounded.trim_end_matches('0').trim_end_matches('.'))
+    } else {
+        rounded
+    }
+}
+
+/// Formats solved problems as an aligned table, with a row for each problem's sum, best result,
+/// best pairing (smaller number first) and runs to solve. Unsolved problems show a dash for each result.
+pub fn format_problem_table(problems: &[NumberPairingProblem]) -> String {
+    let header = ["sum", "best", "first", "second", "runs"].map(String::from);
+    let rows: Vec<[String; 5]> = problems.iter().map(|problem| {
+        let sum = format_float(&problem.sum, &4);
+        match problem.results().and_then(|results| results.best_pairing().first().map(|pairing| (results, pairing))) {
+            Some((results, pairing)) => {
+                let (low, high) = pairing.as_sorted();
+                [sum, format_float(&results.best(), &4), format_float(&low, &4), format_float(&high, &4), problem.runs_to_solve().to_string()]
+            }
+            None => [sum, String::from("-"), String::from("-"), String::from("-"), String::from("-")],
+        }
+    }).collect();
+    let mut widths = header.clone().map(|cell| cell.len());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let format_row = |row: &[String; 5]| -> String {
+        let cells: Vec<String> = row.iter().zip(&widths).map(|(cell, width)| format!("{:>1$}", cell, width)).collect();
+        cells.join(" | ")
+    };
+    let header_row = format_row(&header);
+    let mut table = format!("{}\n{}\n", header_row, make_line(header_row.chars().count()));
+    for row in &rows {
+        table.push_str(&format_row(row));
+        table.push('\n');
+    }
+    table
+}
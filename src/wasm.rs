@@ -0,0 +1,23 @@
+// Bindings for calling the solver from JavaScript (e.g. in a web demo)
+
+use crate::defs::dto::PairingDto;
+use crate::defs::number_pairing_problem::NumberPairingProblem;
+use wasm_bindgen::prelude::*;
+
+/// Solves the problem for the sum, returning the best pairing (first, second, sum, product,
+/// difference and result) as JSON, or `None` if the sum can't be solved (it isn't finite and positive)
+pub fn solve_to_json(sum: f64) -> Option<String> {
+    let problem = NumberPairingProblem::solve_checked(sum).ok()?;
+    let best = problem.results()?.best_pairing().first()?;
+    serde_json::to_string(&PairingDto::from(best)).ok()
+}
+
+/// Solves the problem for the sum, returning the best pairing as a JSON string (for `JSON.parse`),
+/// or `null` if the sum can't be solved
+#[wasm_bindgen]
+pub fn solve(sum: f64) -> JsValue {
+    match solve_to_json(sum) {
+        Some(json) => JsValue::from_str(&json),
+        None => JsValue::NULL,
+    }
+}
@@ -0,0 +1,40 @@
+use highest_number_pairings::{ Goal, NumberPairing, NumberPairingProblem, ProductTimesDifference };
+
+/// Values are compared relative to the analytic optimum, which grows with the cube of the sum
+const RELATIVE_TOLERANCE: f64 = 0.000_000_001;
+
+#[test]
+fn the_numeric_search_matches_the_analytic_optimum() {
+    for sum in [0.5, 1.0, 8.0, 10.0, 123.4, 1000.0] {
+        let analytic = NumberPairingProblem::solve_analytic(sum);
+        let numeric = NumberPairingProblem::solve_with(sum, false, false, ProductTimesDifference, Goal::Maximize);
+        let best = numeric.results().unwrap().best();
+        assert!((best - analytic.result()).abs() <= analytic.result() * RELATIVE_TOLERANCE, "sum {}: {} vs {}", sum, best, analytic.result());
+        let first = numeric.results().unwrap().best_pairing()[0].as_sorted().0;
+        assert!((first - analytic.first()).abs() <= sum * 0.000_1, "sum {}: {} vs {}", sum, first, analytic.first());
+    }
+}
+
+#[test]
+fn the_analytic_optimum_is_the_optimal_deviation_ratio_from_the_center() {
+    let optimum = NumberPairingProblem::solve_analytic(8.0);
+    assert!((optimum.deviation() - 8.0 / (2.0 * 3.0_f64.sqrt())).abs() < 1e-12);
+    assert!((optimum.result() - 49.267_222_970_848).abs() < 1e-9);
+}
+
+#[test]
+fn solving_exactly_gives_the_analytic_optimum() {
+    let exact = NumberPairingProblem::solve_with(8.0, false, true, ProductTimesDifference, Goal::Maximize);
+    assert_eq!(exact.results().unwrap().best(), NumberPairingProblem::solve_analytic(8.0).result());
+}
+
+#[test]
+fn the_optimal_pairing_matches_the_solved_best() {
+    for sum in [1.0, 8.0, 1000.0] {
+        let optimal = NumberPairing::optimal_for(sum);
+        assert_eq!(optimal.deviation(), sum * NumberPairing::OPTIMAL_DEVIATION_RATIO);
+        let best = NumberPairingProblem::builder().solve(sum).results().unwrap().best();
+        assert!((optimal.result() - best).abs() <= optimal.result() * RELATIVE_TOLERANCE, "sum {}: {} vs {}", sum, optimal.result(), best);
+    }
+    assert!((NumberPairing::OPTIMAL_DEVIATION_RATIO - 1.0 / (2.0 * 3.0_f64.sqrt())).abs() < 1e-15);
+}
@@ -0,0 +1,20 @@
+use highest_number_pairings::NumberPairingProblem;
+
+#[test]
+fn batch_results_keep_the_input_order_and_scale_with_the_cube_of_the_sum() {
+    let sums = [2.0, 8.0, 16.0];
+    let problems = NumberPairingProblem::solve_batch(&sums, true);
+    assert_eq!(problems.len(), sums.len());
+    for (problem, sum) in problems.iter().zip(sums) {
+        let single = NumberPairingProblem::builder().solve(sum);
+        assert_eq!(problem.results().unwrap().best(), single.results().unwrap().best());
+        let pairing = problem.results().unwrap().best_pairing()[0];
+        assert_eq!(pairing.first() + pairing.second(), sum);
+    }
+    let best: Vec<f64> = problems.iter().map(|problem| problem.results().unwrap().best()).collect();
+    for (index, sum) in sums.iter().enumerate().skip(1) {
+        let expected_ratio = (sum / sums[0]).powi(3);
+        let ratio = best[index] / best[0];
+        assert!((ratio - expected_ratio).abs() <= expected_ratio * 1e-6, "sum {}: {} vs {}", sum, ratio, expected_ratio);
+    }
+}
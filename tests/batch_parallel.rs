@@ -0,0 +1,18 @@
+#![cfg(feature = "rayon")]
+
+use highest_number_pairings::NumberPairingProblem;
+
+#[test]
+fn parallel_and_sequential_batches_are_identical() {
+    let sums: Vec<f64> = (1..=50).map(|index| index as f64 * 1.5).collect();
+    let sequential = NumberPairingProblem::solve_batch(&sums, true);
+    let parallel = NumberPairingProblem::solve_batch_parallel(&sums, true);
+    assert_eq!(parallel.len(), sequential.len());
+    for (parallel, sequential) in parallel.iter().zip(&sequential) {
+        assert_eq!(parallel.runs_to_solve(), sequential.runs_to_solve());
+        let (parallel, sequential) = (parallel.results().unwrap(), sequential.results().unwrap());
+        assert_eq!(parallel.best(), sequential.best());
+        assert_eq!(parallel.best_pairing(), sequential.best_pairing());
+        assert_eq!(parallel.other_results(), sequential.other_results());
+    }
+}
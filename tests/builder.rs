@@ -0,0 +1,37 @@
+use highest_number_pairings::NumberPairingProblem;
+
+#[test]
+fn more_runs_give_a_result_at_least_as_good() {
+    for sum in [1.0, 8.0, 1000.0] {
+        let mut previous = f64::NEG_INFINITY;
+        for max_runs in [1, 2, 3, 5, 10, 40] {
+            let best = NumberPairingProblem::builder().max_runs(max_runs).solve(sum).results().unwrap().best();
+            assert!(best >= previous, "sum {}: {} runs gave {}, fewer gave {}", sum, max_runs, best, previous);
+            previous = best;
+        }
+    }
+}
+
+#[test]
+fn the_builder_defaults_match_solve_default() {
+    let built = NumberPairingProblem::builder().solve(8.0);
+    let default = NumberPairingProblem::solve_default();
+    assert_eq!(built.runs_to_solve(), default.runs_to_solve());
+    assert_eq!(built.results().unwrap().best(), default.results().unwrap().best());
+}
+
+#[test]
+fn the_bounds_limit_the_search() {
+    let problem = NumberPairingProblem::builder().bounds(3.0, 4.0).collect_other_results(false).solve(8.0);
+    let results = problem.results().unwrap();
+    assert!(results.best_pairing().iter().all(|pairing| (3.0..=4.0).contains(&pairing.first())));
+    assert!(results.other_results().is_none());
+}
+
+#[test]
+fn hitting_max_runs_reports_every_run() {
+    for max_runs in [1, 2, 3] {
+        let problem = NumberPairingProblem::builder().max_runs(max_runs).seeded(false).solve(8.0);
+        assert_eq!(problem.runs_to_solve(), max_runs);
+    }
+}
@@ -0,0 +1,23 @@
+use highest_number_pairings::NumberPairing;
+
+fn recomputed(pairing: &NumberPairing) -> f64 {
+    pairing.product() * pairing.difference()
+}
+
+#[test]
+fn the_cached_result_matches_a_recomputation() {
+    for (number, sum) in [(2.0, 8.0), (0.0, 8.0), (4.0, 8.0), (1.690_598_923, 8.0), (123.456, 1000.0), (0.3, 0.7)] {
+        let pairing = NumberPairing::new(number, sum);
+        assert_eq!(pairing.result(), recomputed(&pairing), "{} of {}", number, sum);
+    }
+}
+
+#[test]
+fn the_cached_result_follows_changes_to_the_numbers() {
+    let mut pairing = NumberPairing::new(2.0, 8.0);
+    pairing.set_first(1.0);
+    assert_eq!(pairing.result(), recomputed(&pairing));
+    pairing.set_second(3.0);
+    assert_eq!(pairing.result(), recomputed(&pairing));
+    assert_eq!(pairing.rounded(1).result(), recomputed(&pairing.rounded(1)));
+}
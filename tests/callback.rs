@@ -0,0 +1,36 @@
+use highest_number_pairings::NumberPairingProblem;
+use std::ops::ControlFlow;
+
+#[test]
+fn breaking_after_the_first_run_stops_the_search() {
+    let mut calls = 0;
+    let problem = NumberPairingProblem::solve_with_callback(8.0, true, |_, _| {
+        calls += 1;
+        ControlFlow::Break(())
+    });
+    assert_eq!(calls, 1);
+    assert_eq!(problem.runs_to_solve(), 1);
+    assert!(problem.results().is_some());
+}
+
+#[test]
+fn the_callback_sees_each_run_and_the_best_so_far() {
+    let mut history = Vec::new();
+    let problem = NumberPairingProblem::solve_with_callback(8.0, false, |run_count, best| {
+        history.push((run_count, best.result()));
+        ControlFlow::Continue(())
+    });
+    let runs: Vec<u32> = history.iter().map(|(run_count, _)| *run_count).collect();
+    assert_eq!(runs, (1..=problem.runs_to_solve()).collect::<Vec<u32>>());
+    assert!(history.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+}
+
+#[test]
+fn the_history_never_decreases_and_ends_at_the_best() {
+    for sum in [1.0, 8.0, 1000.0] {
+        let (problem, history) = NumberPairingProblem::solve_with_history(sum, true);
+        assert_eq!(history.len() as u32, problem.runs_to_solve());
+        assert!(history.windows(2).all(|pair| pair[0].0 + 1 == pair[1].0 && pair[0].1 <= pair[1].1), "{:?}", history);
+        assert_eq!(history.last().map(|(_, best)| *best), Some(problem.results().unwrap().best()));
+    }
+}
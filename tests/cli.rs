@@ -0,0 +1,89 @@
+use std::io::Write;
+use std::process::{ Command, Stdio };
+
+/// Runs the binary with the given arguments, returning its stdout
+fn run(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_highest_number_pairings"))
+        .args(args)
+        .output()
+        .expect("the binary can be run");
+    assert!(output.status.success(), "exited with {}", output.status);
+    String::from_utf8(output.stdout).expect("the output is UTF-8")
+}
+
+/// Runs the binary with the given arguments and environment variable, returning its stdout and stderr
+fn run_with_env(args: &[&str], key: &str, value: &str) -> (String, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_highest_number_pairings"))
+        .args(args)
+        .env(key, value)
+        .output()
+        .expect("the binary can be run");
+    assert!(output.status.success(), "exited with {}", output.status);
+    let stdout = String::from_utf8(output.stdout).expect("the output is UTF-8");
+    let stderr = String::from_utf8(output.stderr).expect("the errors are UTF-8");
+    (stdout, stderr)
+}
+
+/// Runs the binary with the given arguments, writing the input to its stdin, returning its stdout and stderr
+fn run_with_stdin(args: &[&str], input: &str) -> (String, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_highest_number_pairings"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("the binary can be run");
+    child.stdin.take().expect("stdin is piped").write_all(input.as_bytes()).expect("the input can be written");
+    let output = child.wait_with_output().expect("the binary finishes");
+    assert!(output.status.success(), "exited with {}", output.status);
+    let stdout = String::from_utf8(output.stdout).expect("the output is UTF-8");
+    let stderr = String::from_utf8(output.stderr).expect("the errors are UTF-8");
+    (stdout, stderr)
+}
+
+#[test]
+fn precision_sets_the_decimal_places_shown() {
+    let output = run(&["--precision", "6", "--no-other"]);
+    let line = output.lines().find(|line| line.contains("difference:")).expect("a pairing is printed");
+    for label in ["difference: ", "product: "] {
+        let value = line.split(label).nth(1).and_then(|rest| rest.split([',', ' ']).next()).expect("the value is printed");
+        assert_eq!(value.split('.').nth(1).map(str::len), Some(6), "{}", line);
+    }
+}
+
+#[test]
+fn each_stdin_line_is_solved_and_invalid_lines_are_skipped() {
+    let (output, warnings) = run_with_stdin(&["--stdin", "--no-other"], "8\nabc\n10\n");
+    let problems: Vec<&str> = output.lines().filter(|line| line.starts_with("Find two numbers")).collect();
+    assert_eq!(problems.len(), 2, "{}", output);
+    assert!(problems[0].contains("add up to 8,"), "{}", problems[0]);
+    assert!(problems[1].contains("add up to 10,"), "{}", problems[1]);
+    assert_eq!(output.lines().filter(|line| line.starts_with("Best Result:")).count(), 2, "{}", output);
+    assert!(warnings.contains("skipping line 2"), "{}", warnings);
+}
+
+#[test]
+fn explain_shows_the_solved_deviation() {
+    let output = run(&["8", "--explain", "--no-other"]);
+    assert!(output.contains("Derivation:"), "{}", output);
+    assert!(output.contains("t = S/(2√3) = 2.3094"), "{}", output);
+    assert!(output.contains("S/2 - t = 1.6906 and S/2 + t = 6.3094"), "{}", output);
+    assert!(!run(&["8", "--no-other"]).contains("Derivation:"));
+}
+
+#[test]
+fn hnp_max_runs_caps_the_runs() {
+    let (output, warnings) = run_with_env(&["8", "--no-other"], "HNP_MAX_RUNS", "2");
+    assert!(output.contains("(Solved in 2 runs)"), "{}", output);
+    assert!(warnings.is_empty(), "{}", warnings);
+}
+
+#[test]
+fn an_invalid_hnp_max_runs_falls_back_to_the_default() {
+    let default = run(&["8", "--no-other"]);
+    for value in ["0", "many", "-3"] {
+        let (output, warnings) = run_with_env(&["8", "--no-other"], "HNP_MAX_RUNS", value);
+        assert_eq!(output, default);
+        assert!(warnings.contains("ignoring HNP_MAX_RUNS"), "{}", warnings);
+    }
+}
@@ -0,0 +1,22 @@
+use highest_number_pairings::NumberPairingProblem;
+
+#[test]
+fn a_cloned_problem_displays_identically() {
+    let problem = NumberPairingProblem::solve_default();
+    let clone = problem.clone();
+    assert_eq!(clone.view().to_string(), problem.view().to_string());
+    assert_eq!(clone.runs_to_solve(), problem.runs_to_solve());
+}
+
+#[test]
+fn changing_cloned_results_leaves_the_original_alone() {
+    let problem = NumberPairingProblem::builder().max_runs(2).seeded(false).solve(8.0);
+    let original = problem.results().unwrap();
+    let before = original.to_string();
+    let mut clone = original.clone();
+    clone.merge(NumberPairingProblem::builder().solve(8.0).results().unwrap().clone()).expect("the sums match");
+    assert!(clone.best() > original.best());
+    assert_ne!(clone.to_string(), before);
+    assert_eq!(original.to_string(), before);
+    assert_eq!(problem.results().unwrap().best_pairing(), original.best_pairing());
+}
@@ -0,0 +1,21 @@
+use highest_number_pairings::NumberPairingProblem;
+use std::cmp::Ordering;
+
+#[test]
+fn solved_problems_sort_into_ascending_best() {
+    let mut problems: Vec<NumberPairingProblem> = [1.0, 10.0, 5.0].iter().map(|sum| NumberPairingProblem::builder().solve(*sum)).collect();
+    problems.sort_by(NumberPairingProblem::compare);
+    let sums: Vec<f64> = problems.iter().map(|problem| problem.sum).collect();
+    assert_eq!(sums, [1.0, 5.0, 10.0]);
+    let best: Vec<f64> = problems.iter().map(|problem| problem.results().unwrap().best()).collect();
+    assert!(best.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn unsolved_problems_are_less_than_solved_ones() {
+    let unsolved = NumberPairingProblem::solve_constrained(8.0, 9.0, 10.0, true);
+    let solved = NumberPairingProblem::builder().solve(1.0);
+    assert_eq!(unsolved.compare(&solved), Ordering::Less);
+    assert_eq!(solved.compare(&unsolved), Ordering::Greater);
+    assert_eq!(unsolved.compare(&unsolved), Ordering::Equal);
+}
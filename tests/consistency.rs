@@ -0,0 +1,31 @@
+use highest_number_pairings::NumberPairing;
+use std::convert::TryFrom;
+
+#[test]
+fn pairings_at_the_edges_add_up_to_their_sum() {
+    assert!(NumberPairing::new(0.1, 0.3).is_consistent());
+    assert!(NumberPairing::new(1e300, 1e300).is_consistent());
+    assert!(NumberPairing::new(1.0 / 3.0, 1e-12).is_consistent());
+    assert!(NumberPairing::new(123_456.789, 1_000_000.000_001).is_consistent());
+}
+
+#[test]
+fn every_constructor_makes_consistent_pairings() {
+    assert!(NumberPairing::default(2.7).is_consistent());
+    assert!(NumberPairing::default(100.0).is_consistent());
+    assert!(NumberPairing::try_new(0.1, 0.3).unwrap().is_consistent());
+    assert!(NumberPairing::try_new(123_456.789, 1e15).unwrap().is_consistent());
+    assert!(NumberPairing::try_from((0.1, 0.2)).unwrap().is_consistent());
+    assert!(NumberPairing::try_from((1e300, 1.0)).unwrap().is_consistent());
+    assert!(NumberPairing::from_deviation(1e-6, 1e-7).is_consistent());
+    assert!(NumberPairing::optimal_for(1e9).is_consistent());
+}
+
+#[test]
+fn the_tolerance_grows_with_sums_over_one() {
+    // Adding the numbers back up is off by a rounding error in the last place, which is more than
+    // the minimum precision for a sum this large, but still within the tolerance scaled to the sum
+    let pairing = NumberPairing::new(0.1, 1e12);
+    assert!((pairing.first() + pairing.second() - pairing.sum).abs() <= pairing.sum * f64::EPSILON);
+    assert!(pairing.is_consistent());
+}
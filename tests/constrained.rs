@@ -0,0 +1,17 @@
+use highest_number_pairings::NumberPairingProblem;
+
+#[test]
+fn a_band_excluding_the_optimum_is_solved_at_its_edge() {
+    let problem = NumberPairingProblem::solve_constrained(8.0, 2.5, 3.0, true);
+    let results = problem.results().expect("a solved problem has results");
+    let best_pairing = results.best_pairing()[0];
+    assert!((best_pairing.first() - 2.5).abs() < 0.000_001, "{:?}", best_pairing);
+    assert!(results.iter().all(|pairing| pairing.first() >= 2.5 && pairing.first() <= 3.0));
+}
+
+#[test]
+fn a_band_outside_the_sum_is_left_unsolved() {
+    let problem = NumberPairingProblem::solve_constrained(8.0, 10.0, 12.0, true);
+    assert!(!problem.is_solved());
+    assert_eq!(problem.runs_to_solve(), 0);
+}
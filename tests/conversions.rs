@@ -0,0 +1,23 @@
+use highest_number_pairings::{ NumberPairing, PairingError };
+use std::convert::TryFrom;
+
+#[test]
+fn a_tuple_round_trips_through_a_pairing() {
+    let pairing = NumberPairing::try_from((2.0, 6.0)).expect("the numbers are valid");
+    assert_eq!((pairing.first(), pairing.second()), (2.0, 6.0));
+    let tuple: (f64, f64) = pairing.into();
+    assert_eq!(tuple, (2.0, 6.0));
+}
+
+#[test]
+fn a_pairing_round_trips_through_a_tuple() {
+    let pairing = NumberPairing::new(2.0, 8.0);
+    let tuple: (f64, f64) = pairing.into();
+    assert_eq!(NumberPairing::try_from(tuple), Ok(pairing));
+}
+
+#[test]
+fn negative_numbers_are_an_error() {
+    assert_eq!(NumberPairing::try_from((-2.0, 6.0)), Err(PairingError::NumberNegative));
+    assert_eq!(NumberPairing::try_from((2.0, -6.0)), Err(PairingError::NumberNegative));
+}
@@ -0,0 +1,26 @@
+use highest_number_pairings::NumberPairingProblem;
+
+#[test]
+fn the_csv_has_a_header_and_a_row_per_pairing() {
+    let problem = NumberPairingProblem::solve_default();
+    let results = problem.results().unwrap();
+    let csv = results.to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "first,second,product,difference,result");
+    let expected_rows = results.best_pairing().len() + results.other_results().map_or(0, <[_]>::len);
+    assert_eq!(lines.len(), expected_rows + 1);
+    for row in &lines[1..] {
+        let values: Vec<f64> = row.split(',').map(|value| value.parse().expect("each value is a number")).collect();
+        assert_eq!(values.len(), 5, "{}", row);
+    }
+}
+
+#[test]
+fn without_other_results_only_the_best_pairings_are_written() {
+    let problem = NumberPairingProblem::builder().collect_other_results(false).solve(8.0);
+    let results = problem.results().unwrap();
+    let csv = results.to_csv();
+    assert_eq!(csv.lines().count(), results.best_pairing().len() + 1);
+    let first_row: Vec<&str> = csv.lines().nth(1).unwrap().split(',').collect();
+    assert_eq!(first_row[4], results.best().to_string());
+}
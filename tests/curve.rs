@@ -0,0 +1,25 @@
+use highest_number_pairings::NumberPairingProblem;
+
+#[test]
+fn the_sampled_peak_is_near_the_optimum() {
+    let sum = 8.0;
+    let curve = NumberPairingProblem::sample_curve(sum, 1001);
+    assert_eq!(curve.len(), 1001);
+    let (peak, _) = curve.iter().copied().fold((0.0, f64::NEG_INFINITY), |best, point| if point.1 > best.1 { point } else { best });
+    let expected = sum / 2.0 - sum / (2.0 * 3.0_f64.sqrt());
+    assert!((peak - expected).abs() <= sum / 1000.0, "{} vs {}", peak, expected);
+}
+
+#[test]
+fn the_curve_includes_both_endpoints() {
+    let curve = NumberPairingProblem::sample_curve(8.0, 7);
+    assert_eq!(curve.first(), Some(&(0.0, 0.0)));
+    assert_eq!(curve.last(), Some(&(8.0, 0.0)));
+}
+
+#[test]
+fn fewer_than_two_points_gives_just_the_endpoints() {
+    for points in [0, 1] {
+        assert_eq!(NumberPairingProblem::sample_curve(8.0, points), [(0.0, 0.0), (8.0, 0.0)]);
+    }
+}
@@ -0,0 +1,17 @@
+use highest_number_pairings::{ NumberPairing, NumberPairingProblem };
+
+#[test]
+fn pairings_debug_with_their_numbers_and_result() {
+    let debug = format!("{:?}", NumberPairing::new(2.0, 8.0));
+    for field in ["first", "second", "product", "difference", "result"] {
+        assert!(debug.contains(field), "{}", debug);
+    }
+    assert!(debug.contains("48"), "{}", debug);
+}
+
+#[test]
+fn problems_and_results_debug() {
+    let problem = NumberPairingProblem::solve_default();
+    assert!(!format!("{:?}", problem).is_empty());
+    assert!(!format!("{:?}", problem.results().unwrap()).is_empty());
+}
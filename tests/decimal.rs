@@ -0,0 +1,28 @@
+#![cfg(feature = "decimal")]
+
+use highest_number_pairings::{ DecimalPairing, NumberPairing };
+use rust_decimal::prelude::ToPrimitive;
+
+#[test]
+fn decimal_results_match_float_results() {
+    for (first, sum) in [(2.0, 8.0), (1.5, 8.0), (0.25, 1.0), (1.690_598_923_241_497, 8.0)] {
+        let pairing = NumberPairing::new(first, sum);
+        let decimal = DecimalPairing::from_pairing(&pairing).expect("the numbers fit in a decimal");
+        let result = decimal.result().and_then(|result| result.to_f64()).expect("the result fits in a decimal");
+        assert!((result - pairing.result()).abs() < 0.000_000_001, "{} vs {}", result, pairing.result());
+    }
+}
+
+#[test]
+fn whole_number_pairings_are_exact() {
+    let decimal = DecimalPairing::from_pairing(&NumberPairing::new(2.0, 8.0)).expect("the numbers fit in a decimal");
+    assert_eq!(decimal.result().map(|result| result.to_string()), Some(String::from("48")));
+    assert_eq!(decimal.to_string(), "2 and 6 -> 8 (difference: 4, product: 12 -> result: 48)");
+}
+
+#[test]
+fn overflowing_results_are_reported_rather_than_panicking() {
+    let decimal = DecimalPairing::from_pairing(&NumberPairing::optimal_for(1e10)).expect("the numbers fit in a decimal");
+    assert_eq!(decimal.result(), None);
+    assert!(decimal.to_string().ends_with("result: overflow)"));
+}
@@ -0,0 +1,27 @@
+use highest_number_pairings::NumberPairingProblem;
+
+#[test]
+fn the_best_pairings_have_no_mirror_duplicates() {
+    let mut problems = vec![NumberPairingProblem::solve_default()];
+    for sum in [1.0, 2.0, 8.0, 1000.0] {
+        problems.push(NumberPairingProblem::builder().solve(sum));
+        problems.push(NumberPairingProblem::builder().seeded(false).solve(sum));
+    }
+    for problem in &problems {
+        let best_pairing = problem.results().unwrap().best_pairing();
+        for (index, pairing) in best_pairing.iter().enumerate() {
+            assert!(best_pairing[index + 1..].iter().all(|other| other != pairing), "{:?}", best_pairing);
+        }
+    }
+}
+
+#[test]
+fn tied_best_pairings_are_ordered_by_their_first_number() {
+    // A sum of 1 has several best pairings within the minimum precision of each other
+    let problem = NumberPairingProblem::builder().seeded(false).solve(1.0);
+    let best_pairing = problem.results().unwrap().best_pairing();
+    assert!(best_pairing.len() > 1, "{:?}", best_pairing);
+    assert!(best_pairing.windows(2).all(|pair| pair[0].first() < pair[1].first()), "{:?}", best_pairing);
+    let again = NumberPairingProblem::builder().seeded(false).solve(1.0);
+    assert_eq!(format!("{:?}", again.results().unwrap().best_pairing()), format!("{:?}", best_pairing));
+}
@@ -0,0 +1,26 @@
+use highest_number_pairings::{ NumberPairing, NumberPairingProblem };
+
+#[test]
+fn from_deviation_round_trips_through_deviation() {
+    for (sum, deviation) in [(8.0, 0.0), (8.0, 2.0), (8.0, 4.0), (1.0, 0.25), (1000.0, 288.675)] {
+        let pairing = NumberPairing::from_deviation(sum, deviation);
+        assert!((pairing.deviation() - deviation).abs() < 1e-9, "{} vs {}", pairing.deviation(), deviation);
+        assert_eq!(pairing.center(), sum / 2.0);
+        assert_eq!(pairing.first() + pairing.second(), sum);
+    }
+}
+
+#[test]
+fn deviation_round_trips_through_from_deviation() {
+    let pairing = NumberPairing::new(2.0, 8.0);
+    assert_eq!(pairing.center(), 4.0);
+    assert_eq!(pairing.deviation(), 2.0);
+    assert_eq!(NumberPairing::from_deviation(pairing.sum, pairing.deviation()), pairing);
+}
+
+#[test]
+fn the_default_solve_is_two_point_three_from_the_center() {
+    let problem = NumberPairingProblem::solve_default();
+    let deviation = problem.results().unwrap().optimal_deviation().expect("the default solve has a best pairing");
+    assert!((deviation - 8.0 / (2.0 * 3.0_f64.sqrt())).abs() < 0.000_1, "{}", deviation);
+}
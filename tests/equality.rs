@@ -0,0 +1,31 @@
+use highest_number_pairings::NumberPairing;
+use std::collections::HashSet;
+
+#[test]
+fn pairings_differing_by_rounding_error_are_approximately_equal() {
+    let pairing = NumberPairing::new(2.0, 8.0);
+    let rounded = NumberPairing::new(2.0 + 1e-12, 8.0);
+    assert!(pairing.approx_eq(&rounded, NumberPairing::minimum_precision()));
+    assert!(pairing.approx_eq(&NumberPairing::new(6.0 - 1e-12, 8.0), NumberPairing::minimum_precision()));
+    assert!(!pairing.approx_eq(&NumberPairing::new(2.1, 8.0), NumberPairing::minimum_precision()));
+}
+
+#[test]
+fn pairings_within_the_minimum_precision_are_equal() {
+    let pairing = NumberPairing::new(2.0, 8.0);
+    assert_eq!(pairing, NumberPairing::new(2.0 + 1e-12, 8.0));
+    assert_eq!(pairing, NumberPairing::new(6.0 - 1e-12, 8.0));
+    assert!(pairing != NumberPairing::new(2.0 + 1e-12, 8.5));
+}
+
+#[test]
+fn equality_stays_consistent_with_hashing() {
+    let pairing = NumberPairing::new(1.0, 8.0);
+    let nearby = NumberPairing::new(1.0 + 5e-11, 8.0);
+    let mirror = NumberPairing::new(7.0 - 5e-11, 8.0);
+    assert_eq!(pairing, nearby);
+    assert_eq!(pairing, mirror);
+    assert!(pairing != NumberPairing::new(1.1, 8.0));
+    let set: HashSet<NumberPairing> = [pairing, nearby, mirror].iter().copied().collect();
+    assert_eq!(set.len(), 1);
+}
@@ -0,0 +1,34 @@
+use highest_number_pairings::{ NumberPairing, NumberPairingProblem };
+
+#[test]
+fn small_results_can_be_absolutely_but_not_relatively_equivalent() {
+    let pairing = NumberPairing::optimal_for(0.001);
+    let other = NumberPairing::optimal_for(0.0011);
+    assert!(pairing.is_equivalent_to(&other));
+    assert!(!pairing.is_relatively_equivalent_to(&other, 0.01));
+}
+
+#[test]
+fn large_results_can_be_relatively_but_not_absolutely_equivalent() {
+    let pairing = NumberPairing::optimal_for(1000.0);
+    let other = NumberPairing::optimal_for(1000.001);
+    assert!(!pairing.is_equivalent_to(&other));
+    assert!(pairing.is_relatively_equivalent_to(&other, 0.000_01));
+}
+
+#[test]
+fn equivalence_depends_on_the_tolerance() {
+    let pairing = NumberPairing::new(2.0, 8.0);
+    let other = NumberPairing::new(2.001, 8.0);
+    assert!(pairing.is_equivalent_within(&other, 0.01));
+    assert!(!pairing.is_equivalent_within(&other, 0.000_1));
+    assert_eq!(pairing.is_equivalent_to(&other), pairing.is_equivalent_within(&other, NumberPairing::minimum_precision()));
+}
+
+#[test]
+fn a_looser_tolerance_stops_the_search_sooner() {
+    let loose = NumberPairingProblem::builder().minimum_precision(0.001).seeded(false).solve(8.0);
+    let strict = NumberPairingProblem::builder().minimum_precision(0.000_000_000_1).seeded(false).solve(8.0);
+    assert!(loose.runs_to_solve() < strict.runs_to_solve(), "{} vs {}", loose.runs_to_solve(), strict.runs_to_solve());
+    assert!(loose.results().unwrap().best() <= strict.results().unwrap().best());
+}
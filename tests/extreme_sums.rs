@@ -0,0 +1,42 @@
+use highest_number_pairings::NumberPairingProblem;
+
+#[test]
+fn a_sum_of_a_billion_gives_a_sane_pairing() {
+    let sum = 1e9;
+    let problem = NumberPairingProblem::builder().solve(sum);
+    let results = problem.results().expect("the problem is solved");
+    let analytic = NumberPairingProblem::solve_analytic(sum);
+    assert!(results.best().is_finite());
+    assert!((results.best() - analytic.result()).abs() <= analytic.result() * 1e-9, "{} vs {}", results.best(), analytic.result());
+    for pairing in results.best_pairing() {
+        assert!(pairing.first() >= 0.0 && pairing.first() <= sum);
+        assert_eq!(pairing.first() + pairing.second(), sum);
+        // Ties are within the minimum precision relative to the result, which is a wider band of numbers near the flat peak
+        assert!((pairing.as_sorted().0 - analytic.first()).abs() <= sum * 1e-5);
+    }
+}
+
+#[test]
+fn a_tiny_sum_matches_the_analytic_optimum() {
+    for sum in [1e-6, 1e-9] {
+        let problem = NumberPairingProblem::builder().solve(sum);
+        let best = problem.results().unwrap().best();
+        let analytic = NumberPairingProblem::solve_analytic(sum);
+        assert!(best > 0.0, "sum {}: {}", sum, best);
+        assert!((best - analytic.result()).abs() <= analytic.result() * 1e-9, "sum {}: {} vs {}", sum, best, analytic.result());
+        let first = problem.results().unwrap().best_pairing()[0].as_sorted().0;
+        assert!((first - analytic.first()).abs() <= sum * 0.000_1, "sum {}: {} vs {}", sum, first, analytic.first());
+    }
+}
+
+#[test]
+fn a_tiny_sum_improves_in_the_main_search_runs() {
+    // Results for a sum this small are all below the minimum precision, so ties have to be scaled to the sum,
+    // or the first run would tie with the initial value of 0 and leave the optimum to the refinement after the search
+    let sum = 1e-6;
+    let (problem, history) = NumberPairingProblem::solve_with_history(sum, false);
+    let analytic = NumberPairingProblem::solve_analytic(sum);
+    assert!(history.len() > 1, "{:?}", history);
+    assert!((history[0].1 - analytic.result()).abs() <= analytic.result() * 1e-9, "{:?}", history);
+    assert!((problem.results().unwrap().best() - analytic.result()).abs() <= analytic.result() * 1e-9);
+}
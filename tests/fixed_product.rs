@@ -0,0 +1,25 @@
+use highest_number_pairings::FixedProductProblem;
+
+#[test]
+fn the_smallest_sum_for_a_product_of_sixteen_is_four_and_four() {
+    let pairing = FixedProductProblem::new(16.0).solve();
+    assert_eq!(pairing.as_sorted(), (4.0, 4.0));
+    assert_eq!(pairing.product(), 16.0);
+    assert_eq!(pairing.result(), 0.0);
+}
+
+#[test]
+fn a_difference_of_six_for_a_product_of_sixteen_is_two_and_eight() {
+    let pairing = FixedProductProblem::new(16.0).pairing_with_difference(6.0);
+    assert_eq!(pairing.as_sorted(), (2.0, 8.0));
+    assert_eq!(pairing.product(), 16.0);
+}
+
+#[test]
+fn a_result_of_ninety_six_for_a_product_of_sixteen_is_two_and_eight() {
+    let problem = FixedProductProblem::new(16.0);
+    let pairing = problem.pairing_with_result(96.0).expect("a positive product has a pairing for every result");
+    assert_eq!(pairing.as_sorted(), (2.0, 8.0));
+    assert_eq!(pairing.result(), 96.0);
+    assert!(FixedProductProblem::new(0.0).pairing_with_result(1.0).is_none());
+}
@@ -0,0 +1,54 @@
+use highest_number_pairings::NumberPairing;
+
+#[test]
+fn display_is_four_decimals_without_scientific_notation() {
+    let pairing = NumberPairing::new(1.0 / 3.0, 8.0);
+    assert_eq!(pairing.to_string(), pairing.format(4, false));
+    assert!(pairing.to_string().starts_with("0.3333 and 7.6667 -> 8 "), "{}", pairing);
+}
+
+#[test]
+fn more_decimals_can_be_shown() {
+    let pairing = NumberPairing::new(1.0 / 3.0, 8.0);
+    assert!(pairing.format(8, false).starts_with("0.33333333 and 7.66666667 -> 8 "), "{}", pairing.format(8, false));
+    assert_eq!(NumberPairing::new(2.0, 8.0).format(8, false), "2 and 6 -> 8 (difference: 4, product: 12 -> result: 48)");
+}
+
+#[test]
+fn large_results_can_be_shown_in_scientific_notation() {
+    let pairing = NumberPairing::new(2e6, 8e6);
+    assert_eq!(pairing.format(2, true), "2.00e6 and 6.00e6 -> 8.00e6 (difference: 4.00e6, product: 1.20e13 -> result: 4.80e19)");
+    assert_eq!(NumberPairing::new(2.0, 8.0).format(0, true), "2e0 and 6e0 -> 8e0 (difference: 4e0, product: 1e1 -> result: 5e1)");
+}
+
+#[test]
+fn the_equation_spells_out_the_product_difference_and_result() {
+    let equation = NumberPairing::new(2.0, 8.0).equation();
+    assert!(equation.contains("2 × 6 = 12"), "{}", equation);
+    assert!(equation.contains("|2 − 6| = 4"), "{}", equation);
+    assert!(equation.contains("12 × 4 = 48"), "{}", equation);
+    assert_eq!(NumberPairing::new(6.0, 8.0).equation(), equation);
+}
+
+#[test]
+fn a_messy_optimum_rounds_to_two_decimals() {
+    let optimum = NumberPairing::optimal_for(8.0);
+    let rounded = optimum.rounded(2);
+    assert_eq!(rounded.first(), 1.69);
+    assert!((rounded.second() - 6.31).abs() < 1e-12, "{}", rounded.second());
+    assert!((rounded.first() + rounded.second() - 8.0).abs() < 1e-12);
+    assert_eq!(rounded.sum, 8.0);
+    assert_eq!(rounded.result(), rounded.product() * rounded.difference());
+}
+
+#[test]
+fn rounding_to_more_decimals_than_an_f64_holds_keeps_the_pairing() {
+    let optimum = NumberPairing::optimal_for(8.0);
+    for decimals in [16, 400, u32::MAX] {
+        let rounded = optimum.rounded(decimals);
+        assert!(rounded.first().is_finite() && rounded.result().is_finite(), "{} decimals: {:?}", decimals, rounded);
+        assert_eq!(rounded.first(), optimum.rounded(15).first());
+    }
+    let huge = NumberPairing::new(1e300, 1e301);
+    assert_eq!(huge.rounded(15).first(), 1e300);
+}
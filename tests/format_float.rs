@@ -0,0 +1,17 @@
+use highest_number_pairings::format_float;
+
+#[test]
+fn integer_zeros_are_kept() {
+    assert_eq!(format_float(&10.0, &0), "10");
+    assert_eq!(format_float(&100.0, &0), "100");
+    assert_eq!(format_float(&10.0, &4), "10");
+    assert_eq!(format_float(&100.0, &4), "100");
+}
+
+#[test]
+fn only_trailing_fractional_zeros_are_trimmed() {
+    assert_eq!(format_float(&0.5000, &4), "0.5");
+    assert_eq!(format_float(&2.5, &4), "2.5");
+    assert_eq!(format_float(&8.0, &4), "8");
+    assert_eq!(format_float(&8.0, &0), "8");
+}
@@ -0,0 +1,49 @@
+use highest_number_pairings::NumberPairing;
+
+#[test]
+fn from_result_recovers_the_deviation() {
+    for (number, sum) in [(3.0, 8.0), (2.5, 8.0), (1.7, 8.0), (0.4, 1.0), (400.0, 1000.0)] {
+        let pairing = NumberPairing::new(number, sum);
+        let recovered = NumberPairing::from_result(pairing.result(), pairing.sum).expect("the result is achievable");
+        assert!((recovered.deviation() - pairing.deviation()).abs() < 1e-6 * sum, "{} vs {}", recovered, pairing);
+    }
+}
+
+#[test]
+fn pairings_further_out_than_the_optimum_recover_their_result() {
+    let pairing = NumberPairing::new(0.5, 8.0);
+    let recovered = NumberPairing::from_result(pairing.result(), pairing.sum).expect("the result is achievable");
+    assert!((recovered.result() - pairing.result()).abs() < 1e-9, "{} vs {}", recovered, pairing);
+    assert!(recovered.deviation() < pairing.deviation());
+}
+
+#[test]
+fn results_beyond_the_optimum_have_no_pairing() {
+    let highest = NumberPairing::optimal_for(8.0).result();
+    assert!(NumberPairing::from_result(highest * 1.01, 8.0).is_none());
+    assert!(NumberPairing::from_result(-1.0, 8.0).is_none());
+}
+
+#[test]
+fn the_optimum_has_just_the_two_mirror_solutions() {
+    let optimum = NumberPairing::optimal_for(8.0);
+    let solutions = NumberPairing::solutions_for_result(optimum.result(), 8.0);
+    assert_eq!(solutions.len(), 2, "{:?}", solutions);
+    assert!(solutions[0].approx_eq(&solutions[1], NumberPairing::minimum_precision()));
+    assert!((solutions[0].first() - solutions[1].second()).abs() < 1e-9);
+    assert!(solutions.iter().all(|solution| (solution.deviation() - optimum.deviation()).abs() < 1e-6));
+}
+
+#[test]
+fn a_result_below_the_optimum_has_four_solutions() {
+    let solutions = NumberPairing::solutions_for_result(48.0, 8.0);
+    assert_eq!(solutions.len(), 4, "{:?}", solutions);
+    assert!(solutions.iter().all(|solution| (solution.result() - 48.0).abs() < 1e-9));
+    assert!(solutions.iter().any(|solution| (solution.as_sorted().0 - 2.0).abs() < 1e-9));
+}
+
+#[test]
+fn results_beyond_the_optimum_have_no_solutions() {
+    let highest = NumberPairing::optimal_for(8.0).result();
+    assert!(NumberPairing::solutions_for_result(highest + 1.0, 8.0).is_empty());
+}
@@ -0,0 +1,19 @@
+use highest_number_pairings::{ Goal, NumberPairing, NumberPairingProblem, ProductTimesDifference };
+
+#[test]
+fn minimizing_a_custom_objective_finds_its_lowest_point() {
+    let distance_from_three = |pairing: &NumberPairing| (pairing.first() - 3.0).abs();
+    let problem = NumberPairingProblem::solve_with(8.0, true, false, distance_from_three, Goal::Minimize);
+    let results = problem.results().expect("a solved problem has results");
+    assert!(results.best() < 0.000_001, "{}", results.best());
+    assert!((results.best_pairing()[0].first() - 3.0).abs() < 0.000_001);
+}
+
+#[test]
+fn minimizing_at_an_endpoint_still_reports_the_best_pairing() {
+    let problem = NumberPairingProblem::solve_with(8.0, true, false, ProductTimesDifference, Goal::Minimize);
+    let results = problem.results().expect("a solved problem has results");
+    assert_eq!(results.best(), 0.0);
+    assert!(!results.best_pairing().is_empty());
+    assert!(results.best_pairing().iter().all(|pairing| pairing.result() == 0.0));
+}
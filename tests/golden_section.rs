@@ -0,0 +1,28 @@
+use highest_number_pairings::{ NumberPairing, NumberPairingProblem };
+use std::sync::Arc;
+use std::sync::atomic::{ AtomicUsize, Ordering };
+
+fn recursive_evaluations(sum: f64) -> (NumberPairingProblem, usize) {
+    let evaluations = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&evaluations);
+    let problem = NumberPairingProblem::builder()
+        .collect_other_results(false)
+        .objective(move |pairing: &NumberPairing| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            pairing.result()
+        })
+        .solve(sum);
+    (problem, evaluations.load(Ordering::Relaxed))
+}
+
+#[test]
+fn golden_section_evaluates_fewer_pairings_than_the_recursive_search() {
+    for sum in [8.0, 1_000.0] {
+        let (recursive, recursive_evaluations) = recursive_evaluations(sum);
+        let golden = NumberPairingProblem::solve_golden_section(sum, false);
+        assert!((golden.runs_to_solve() as usize) < recursive_evaluations, "sum {}: {} vs {}", sum, golden.runs_to_solve(), recursive_evaluations);
+        let golden_best = golden.results().unwrap().best();
+        let recursive_best = recursive.results().unwrap().best();
+        assert!((golden_best - recursive_best).abs() <= recursive_best * 1e-9, "sum {}: {} vs {}", sum, golden_best, recursive_best);
+    }
+}
@@ -0,0 +1,36 @@
+use highest_number_pairings::NumberPairing;
+use std::collections::HashSet;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{ Hash, Hasher };
+
+fn hash_of(pairing: &NumberPairing) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    pairing.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn fractional_parts_below_a_half_are_hashed() {
+    let low = NumberPairing::new(2.3, 8.0);
+    let high = NumberPairing::new(2.7, 8.0);
+    assert_ne!(low, high);
+    assert_ne!(hash_of(&low), hash_of(&high));
+    assert_ne!(hash_of(&NumberPairing::new(2.1, 8.0)), hash_of(&NumberPairing::new(2.2, 8.0)));
+}
+
+#[test]
+fn equal_pairings_hash_equally() {
+    let pairing = NumberPairing::new(2.3, 8.0);
+    assert_eq!(pairing, NumberPairing::new(2.3, 8.0));
+    assert_eq!(hash_of(&pairing), hash_of(&NumberPairing::new(2.3, 8.0)));
+}
+
+#[test]
+fn mirror_pairings_are_one_set_entry() {
+    let pairing = NumberPairing::new(2.0, 8.0);
+    let mirror = NumberPairing::new(6.0, 8.0);
+    assert_eq!(pairing, mirror);
+    assert_eq!(hash_of(&pairing), hash_of(&mirror));
+    let set: HashSet<NumberPairing> = [pairing, mirror].iter().copied().collect();
+    assert_eq!(set.len(), 1);
+}
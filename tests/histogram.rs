@@ -0,0 +1,27 @@
+use highest_number_pairings::NumberPairingProblem;
+
+#[test]
+fn the_counts_add_up_to_every_collected_pairing() {
+    let problem = NumberPairingProblem::solve_default();
+    let results = problem.results().unwrap();
+    let total = results.best_pairing().len() + results.other_results().unwrap().len();
+    for buckets in [1, 3, 10] {
+        let histogram = results.result_histogram(buckets);
+        assert_eq!(histogram.len(), buckets);
+        assert_eq!(histogram.iter().map(|(_, count)| count).sum::<usize>(), total);
+        assert!(histogram.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+}
+
+#[test]
+fn a_single_distinct_result_is_one_bucket() {
+    let problem = NumberPairingProblem::builder().collect_other_results(false).solve(8.0);
+    let results = problem.results().unwrap();
+    assert_eq!(results.result_histogram(5), [(results.best(), results.best_pairing().len())]);
+}
+
+#[test]
+fn no_buckets_is_an_empty_histogram() {
+    let problem = NumberPairingProblem::solve_default();
+    assert!(problem.results().unwrap().result_histogram(0).is_empty());
+}
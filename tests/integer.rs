@@ -0,0 +1,33 @@
+use highest_number_pairings::NumberPairingProblem;
+
+#[test]
+fn eight_splits_best_into_two_and_six() {
+    let best = NumberPairingProblem::solve_integer(8);
+    assert_eq!(best.as_sorted(), (2.0, 6.0));
+    assert_eq!(best.result(), 48.0);
+}
+
+#[test]
+fn the_integer_split_is_the_best_of_every_split() {
+    for sum in [0, 1, 2, 7, 10, 25, 100] {
+        let best = NumberPairingProblem::solve_integer(sum);
+        let sum_f64 = sum as f64;
+        let highest = (0..=sum)
+            .map(|number| number as f64)
+            .map(|number| number * (sum_f64 - number) * (sum_f64 - 2.0 * number).abs())
+            .fold(0.0, f64::max);
+        assert_eq!(best.result(), highest, "sum {}", sum);
+        assert_eq!(best.first().fract(), 0.0);
+    }
+}
+
+#[test]
+fn every_integer_pairing_of_eight_is_listed_best_first() {
+    let pairings = NumberPairingProblem::all_integer_pairings(8);
+    assert_eq!(pairings.len(), 5);
+    assert_eq!(pairings[0].as_sorted(), (2.0, 6.0));
+    assert!(pairings.windows(2).all(|pair| pair[0].result() >= pair[1].result()));
+    let mut firsts: Vec<f64> = pairings.iter().map(|pairing| pairing.first()).collect();
+    firsts.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(firsts, [0.0, 1.0, 2.0, 3.0, 4.0]);
+}
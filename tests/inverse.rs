@@ -0,0 +1,23 @@
+use highest_number_pairings::NumberPairingProblem;
+
+#[test]
+fn the_sum_for_a_target_reproduces_the_target() {
+    for target in [0.5, 48.0, 49.267_222_970_848, 1_000.0, 1e12] {
+        let sum = NumberPairingProblem::sum_for_target_result(target).expect("the target isn't negative");
+        let result = NumberPairingProblem::solve_analytic(sum).result();
+        assert!((result - target).abs() <= target * 1e-12, "target {}: {}", target, result);
+    }
+    let sum = NumberPairingProblem::sum_for_target_result(49.267_222_970_848).unwrap();
+    assert!((sum - 8.0).abs() < 1e-9, "{}", sum);
+}
+
+#[test]
+fn a_target_of_zero_needs_a_sum_of_zero() {
+    assert_eq!(NumberPairingProblem::sum_for_target_result(0.0), Some(0.0));
+}
+
+#[test]
+fn negative_targets_have_no_sum() {
+    assert_eq!(NumberPairingProblem::sum_for_target_result(-1.0), None);
+    assert_eq!(NumberPairingProblem::sum_for_target_result(f64::NAN), None);
+}
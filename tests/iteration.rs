@@ -0,0 +1,29 @@
+use highest_number_pairings::{ NumberPairing, NumberPairingProblem };
+
+// The best pairings are tied within the minimum precision scaled to the sum, rather than exactly
+fn is_tied_with_best(pairing: &NumberPairing, best: f64) -> bool {
+    (pairing.result() - best).abs() <= best * 1e-9
+}
+
+#[test]
+fn iterating_results_yields_the_best_pairings_then_the_others() {
+    let problem = NumberPairingProblem::solve_default();
+    let results = problem.results().unwrap();
+    let yielded: Vec<&NumberPairing> = results.into_iter().collect();
+    let best_count = results.best_pairing().len();
+    assert_eq!(yielded.len(), best_count + results.other_results().unwrap().len());
+    assert!(yielded[..best_count].iter().all(|pairing| is_tied_with_best(pairing, results.best())));
+    assert!(yielded[best_count..].windows(2).all(|pair| pair[0].result() >= pair[1].result()));
+}
+
+#[test]
+fn results_can_be_looped_over_directly() {
+    let problem = NumberPairingProblem::builder().collect_other_results(false).solve(8.0);
+    let results = problem.results().unwrap();
+    let mut count = 0;
+    for pairing in results {
+        assert!(is_tied_with_best(pairing, results.best()));
+        count += 1;
+    }
+    assert_eq!(count, results.best_pairing().len());
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "logging")]
+
+use highest_number_pairings::NumberPairingProblem;
+use log::{ Level, LevelFilter, Log, Metadata, Record };
+use std::sync::Mutex;
+
+/// A logger that keeps every record's level and message
+struct CapturingLogger {
+    records: Mutex<Vec<(Level, String)>>,
+}
+
+impl Log for CapturingLogger {
+    fn enabled(&self, _: &Metadata) -> bool { true }
+
+    fn log(&self, record: &Record) {
+        self.records.lock().unwrap().push((record.level(), record.args().to_string()));
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: CapturingLogger = CapturingLogger { records: Mutex::new(Vec::new()) };
+
+#[test]
+fn a_default_solve_emits_debug_records() {
+    log::set_logger(&LOGGER).expect("no other logger is set");
+    log::set_max_level(LevelFilter::Trace);
+    let problem = NumberPairingProblem::solve_default();
+    let records = LOGGER.records.lock().unwrap();
+    let debug: Vec<&String> = records.iter().filter(|(level, _)| *level == Level::Debug).map(|(_, message)| message).collect();
+    assert!(!debug.is_empty());
+    assert!(debug.iter().any(|message| message.starts_with("Run 1: searching")), "{:?}", debug);
+    assert!(debug.iter().any(|message| message.contains("new overall best")), "{:?}", debug);
+    assert!(records.iter().any(|(level, _)| *level == Level::Trace));
+    assert!(problem.is_solved());
+}
@@ -0,0 +1,20 @@
+use highest_number_pairings::{ make_line, make_line_with };
+
+#[test]
+fn lines_default_to_dashes() {
+    assert_eq!(make_line(5), "-----");
+    assert_eq!(make_line(0), "");
+}
+
+#[test]
+fn lines_can_use_another_character() {
+    assert_eq!(make_line_with(4, '='), "====");
+}
+
+#[test]
+fn multi_byte_characters_are_repeated_whole() {
+    let line = make_line_with(3, '═');
+    assert_eq!(line, "═══");
+    assert_eq!(line.chars().count(), 3);
+    assert_eq!(line.len(), 3 * '═'.len_utf8());
+}
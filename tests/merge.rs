@@ -0,0 +1,48 @@
+use highest_number_pairings::{ Goal, MergeError, NumberPairing, NumberPairingProblem, PoweredProductTimesDifference };
+
+#[test]
+fn merging_a_coarse_and_a_fine_solve_keeps_the_finer_best() {
+    let coarse = NumberPairingProblem::builder().max_runs(2).seeded(false).solve(8.0);
+    let fine = NumberPairingProblem::builder().solve(8.0);
+    let (coarse, fine) = (coarse.results().unwrap().clone(), fine.results().unwrap().clone());
+    assert!(coarse.best() < fine.best());
+    let mut merged = coarse.clone();
+    merged.merge(fine.clone()).expect("the results are for the same sum");
+    assert_eq!(merged.best(), fine.best());
+    assert_eq!(merged.final_precision(), fine.final_precision());
+    // The best pairings are tied within the minimum precision scaled to the sum
+    assert!(merged.best_pairing().iter().all(|pairing| pairing.is_equivalent_within(&fine.best_pairing()[0], fine.best() * 1e-9)));
+    let other = merged.other_results().unwrap();
+    assert!(other.iter().any(|pairing| pairing.approx_eq(&coarse.best_pairing()[0], 0.0)));
+    assert!(other.windows(2).all(|pair| pair[0].result() > pair[1].result()));
+    assert!(other.iter().all(|pairing| pairing.result() < merged.best()));
+}
+
+#[test]
+fn results_for_different_sums_cant_be_merged() {
+    let mut eight = NumberPairingProblem::builder().solve(8.0).results().unwrap().clone();
+    let nine = NumberPairingProblem::builder().solve(9.0).results().unwrap().clone();
+    assert_eq!(eight.merge(nine), Err(MergeError::DifferentSums));
+    assert_eq!(eight.best(), NumberPairingProblem::builder().solve(8.0).results().unwrap().best());
+}
+
+#[test]
+fn merging_minimized_results_keeps_the_lowest() {
+    let distance_from_e = |pairing: &NumberPairing| (pairing.first() - std::f64::consts::E).abs();
+    let solve = |max_runs: u32| NumberPairingProblem::builder().objective(distance_from_e).goal(Goal::Minimize).max_runs(max_runs).solve(8.0);
+    let (coarse, fine) = (solve(2).results().unwrap().clone(), solve(20).results().unwrap().clone());
+    assert!(coarse.best() > fine.best());
+    let mut merged = coarse.clone();
+    merged.merge(fine.clone()).expect("the results have the same sum, objective and goal");
+    assert_eq!(merged.best(), fine.best());
+    assert!((merged.best_pairing()[0].first() - std::f64::consts::E).abs() < 0.000_001);
+}
+
+#[test]
+fn results_with_different_goals_or_objectives_cant_be_merged() {
+    let maximized = NumberPairingProblem::builder().solve(8.0).results().unwrap().clone();
+    let minimized = NumberPairingProblem::builder().goal(Goal::Minimize).solve(8.0).results().unwrap().clone();
+    assert_eq!(maximized.clone().merge(minimized), Err(MergeError::DifferentGoals));
+    let powered = NumberPairingProblem::builder().objective(PoweredProductTimesDifference::new(2, 1)).solve(8.0).results().unwrap().clone();
+    assert_eq!(maximized.clone().merge(powered), Err(MergeError::DifferentObjectives));
+}
@@ -0,0 +1,45 @@
+use highest_number_pairings::{ NumberPairing, NumberPairingProblem };
+
+#[test]
+fn the_normalized_optimum_is_the_same_for_any_sum() {
+    let small = NumberPairingProblem::solve_analytic(8.0).normalized_result();
+    let large = NumberPairingProblem::solve_analytic(80.0).normalized_result();
+    assert!((small - large).abs() < 1e-12, "{} vs {}", small, large);
+    assert!((small - 1.0 / (6.0 * 3.0_f64.sqrt())).abs() < 1e-12, "{}", small);
+}
+
+#[test]
+fn the_normalized_result_is_the_result_over_the_cubed_sum() {
+    let pairing = NumberPairing::new(2.0, 8.0);
+    assert_eq!(pairing.normalized_result(), 48.0 / 512.0);
+}
+
+#[test]
+fn the_optimum_has_an_optimality_ratio_of_one() {
+    let optimum = NumberPairing::optimal_for(8.0);
+    assert!((optimum.optimality_ratio() - 1.0).abs() < 1e-12);
+    assert!(optimum.is_near_optimal(0.0001));
+}
+
+#[test]
+fn the_endpoints_have_an_optimality_ratio_near_zero() {
+    for number in [0.0, 8.0, 1e-9] {
+        let pairing = NumberPairing::new(number, 8.0);
+        assert!(pairing.optimality_ratio() < 1e-6, "{}", pairing.optimality_ratio());
+        assert!(!pairing.is_near_optimal(0.01));
+    }
+    let close = NumberPairing::new(1.7, 8.0);
+    assert!(close.is_near_optimal(0.01) && !close.is_near_optimal(0.000_01), "{}", close.optimality_ratio());
+}
+
+#[test]
+fn the_sensitivity_matches_a_finite_difference() {
+    for sum in [1.0, 8.0, 100.0] {
+        let step = sum * 1e-6;
+        let above = NumberPairingProblem::solve_analytic(sum + step).result();
+        let below = NumberPairingProblem::solve_analytic(sum - step).result();
+        let estimate = (above - below) / (2.0 * step);
+        let derivative = NumberPairing::d_result_d_sum_at_optimum(sum);
+        assert!((derivative - estimate).abs() <= derivative * 1e-6, "sum {}: {} vs {}", sum, derivative, estimate);
+    }
+}
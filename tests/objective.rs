@@ -0,0 +1,75 @@
+use highest_number_pairings::{ NumberPairing, NumberPairingProblem, Objective, PoweredProductTimesDifference, ProductTimesDifference, WeightedProductPlusDifference };
+
+#[test]
+fn the_default_objective_is_unimodal() {
+    assert!(ProductTimesDifference.verify_unimodal(8.0, 1000));
+}
+
+#[test]
+fn a_bimodal_objective_is_not_unimodal() {
+    // Peaks where the first number is 1 and 3, with a dip between them
+    let bimodal = |pairing: &NumberPairing| -> f64 {
+        let first = pairing.first();
+        -(first - 1.0).powi(2) * (first - 3.0).powi(2)
+    };
+    assert!(!bimodal.verify_unimodal(8.0, 1000));
+}
+
+#[test]
+fn the_product_is_highest_away_from_the_optimal_result() {
+    let max_product_pairing = NumberPairing::max_product_pairing(8.0);
+    let optimal = NumberPairing::optimal_for(8.0);
+    assert_eq!(max_product_pairing.product(), NumberPairing::max_product(8.0));
+    assert!(max_product_pairing.product() > optimal.product());
+    assert!(max_product_pairing.result() < optimal.result());
+    assert!(max_product_pairing != optimal);
+}
+
+#[test]
+fn without_the_difference_the_weighted_optimum_is_the_center() {
+    let problem = NumberPairingProblem::builder()
+        .objective(WeightedProductPlusDifference::new(1.0, 0.0))
+        .seeded(false)
+        .solve(8.0);
+    let best_pairing = problem.results().expect("a solved problem has results").best_pairing()[0];
+    assert!((best_pairing.first() - 4.0).abs() < 0.000_001, "{:?}", best_pairing);
+    assert!((best_pairing.product() - NumberPairing::max_product(8.0)).abs() < 0.000_001);
+}
+
+/// Solves for 8 with the product and difference raised to the given powers, returning the smaller best number and the best value
+fn solve_powered(product_exponent: i32, difference_exponent: i32) -> (f64, f64) {
+    let problem = NumberPairingProblem::builder()
+        .collect_other_results(false)
+        .objective(PoweredProductTimesDifference::new(product_exponent, difference_exponent))
+        .solve(8.0);
+    let results = problem.results().unwrap();
+    (results.best_pairing()[0].as_sorted().0, results.best())
+}
+
+#[test]
+fn squaring_the_product_moves_the_optimum_toward_the_center() {
+    let (first, best) = solve_powered(2, 1);
+    // The optimum is a deviation of 4 / √5 from the center
+    let expected = 4.0 - 4.0 / 5.0_f64.sqrt();
+    assert!((first - expected).abs() < 0.000_1, "{} vs {}", first, expected);
+    let pairing = NumberPairing::new(expected, 8.0);
+    assert!((best - pairing.product().powi(2) * pairing.difference()).abs() < 0.000_001 * best, "{}", best);
+    assert!(first > NumberPairing::optimal_for(8.0).first());
+}
+
+#[test]
+fn squaring_the_difference_moves_the_optimum_toward_the_edges() {
+    let (first, best) = solve_powered(1, 2);
+    // The optimum is a deviation of 4 / √2 from the center
+    let expected = 4.0 - 4.0 / 2.0_f64.sqrt();
+    assert!((first - expected).abs() < 0.000_1, "{} vs {}", first, expected);
+    let pairing = NumberPairing::new(expected, 8.0);
+    assert!((best - pairing.product() * pairing.difference().powi(2)).abs() < 0.000_001 * best, "{}", best);
+    assert!(first < NumberPairing::optimal_for(8.0).first());
+}
+
+#[test]
+fn powers_of_one_are_the_original_objective() {
+    let pairing = NumberPairing::new(2.0, 8.0);
+    assert_eq!(PoweredProductTimesDifference::default().evaluate(&pairing), pairing.result());
+}
@@ -0,0 +1,37 @@
+use highest_number_pairings::{ NumberPairing, cmp_by_deviation };
+use std::cmp::Ordering;
+
+#[test]
+fn pairings_within_tolerance_compare_equal() {
+    let lower = NumberPairing::new(1.0, 8.0);
+    let higher = NumberPairing::new(1.0 + 1e-13, 8.0);
+    assert!(lower.is_equivalent_to(&higher));
+    assert_eq!(lower.cmp(&higher), Ordering::Equal);
+    assert_eq!(higher.cmp(&lower), Ordering::Equal);
+    assert_eq!(lower.cmp(&NumberPairing::new(1.1, 8.0)), Ordering::Less);
+}
+
+#[test]
+fn ordering_is_a_total_order_for_near_equal_results() {
+    // Each result is about 0.6e-10 more than the last, so neighbours are equivalent but the ends aren't
+    let mut pairings: Vec<NumberPairing> = (0..500).rev().map(|index| NumberPairing::new(index as f64 * 1e-12, 8.0)).collect();
+    pairings.sort();
+    // Pairings are only out of order by result within the same step of the minimum precision
+    assert!(pairings.windows(2).all(|pair| pair[0].result() < pair[1].result() + NumberPairing::minimum_precision()));
+    assert!(pairings.windows(2).all(|pair| pair[0] <= pair[1]));
+    // Equal is transitive: anything equal to the first is equal to everything else equal to it
+    let equal_to_first: Vec<&NumberPairing> = pairings.iter().filter(|pairing| (*pairing).cmp(&pairings[0]) == Ordering::Equal).collect();
+    assert!(equal_to_first.iter().all(|left| equal_to_first.iter().all(|right| left.cmp(right) == Ordering::Equal)));
+    assert_eq!(pairings[0].cmp(&pairings[499]), Ordering::Less);
+}
+
+#[test]
+fn pairings_sort_by_deviation_most_central_first() {
+    let mut pairings: Vec<NumberPairing> = [0.0, 5.0, 2.0, 4.0, 6.5].iter().map(|number| NumberPairing::new(*number, 8.0)).collect();
+    pairings.sort_by(cmp_by_deviation);
+    let deviations: Vec<f64> = pairings.iter().map(NumberPairing::deviation).collect();
+    assert_eq!(deviations, [0.0, 1.0, 2.0, 2.5, 4.0]);
+    assert_eq!(cmp_by_deviation(&NumberPairing::new(2.0, 8.0), &NumberPairing::new(6.0, 8.0)), Ordering::Equal);
+    // By result, the most central pairing (which has no difference) is the lowest instead
+    assert!(pairings[0] < pairings[2]);
+}
@@ -0,0 +1,36 @@
+use highest_number_pairings::{ NumberPairing, NumberPairingProblem, MINIMUM_PRECISION };
+
+#[test]
+fn a_cluster_of_near_equal_results_collapses_to_one() {
+    // Results fall away from a peak at 1.2, then level out on a plateau (varying by far less than the minimum precision)
+    let plateau = |pairing: &NumberPairing| -> f64 {
+        let low = pairing.as_sorted().0;
+        if low < 2.0 { 10.0 - (low - 1.2).abs() } else { 1.0 + low * 1e-13 }
+    };
+    let on_plateau = |dedup_tolerance: f64| -> usize {
+        let problem = NumberPairingProblem::builder().objective(plateau).initial_precision(0.5).dedup_tolerance(dedup_tolerance).solve(8.0);
+        let other = problem.results().unwrap().other_results().unwrap();
+        other.iter().filter(|pairing| pairing.as_sorted().0 >= 2.0).count()
+    };
+    assert!(on_plateau(0.0) > 1);
+    assert_eq!(on_plateau(MINIMUM_PRECISION), 1);
+}
+
+#[test]
+fn other_results_are_at_least_the_dedup_tolerance_apart() {
+    for tolerance in [MINIMUM_PRECISION, 0.01, 1.0] {
+        let problem = NumberPairingProblem::builder().dedup_tolerance(tolerance).solve(8.0);
+        let other = problem.results().unwrap().other_results().unwrap();
+        assert!(other.windows(2).all(|pair| pair[0].result() - pair[1].result() >= tolerance), "{:?}", other);
+    }
+}
+
+#[test]
+fn larger_dedup_tolerances_give_fewer_other_results() {
+    let counts: Vec<usize> = [0.0, 0.000_001, 0.01, 0.1, 1.0]
+        .iter()
+        .map(|tolerance| NumberPairingProblem::builder().dedup_tolerance(*tolerance).solve(8.0).results().unwrap().other_results().unwrap().len())
+        .collect();
+    assert!(counts.windows(2).all(|pair| pair[0] >= pair[1]), "{:?}", counts);
+    assert!(counts[0] > counts[counts.len() - 1], "{:?}", counts);
+}
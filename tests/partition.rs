@@ -0,0 +1,51 @@
+use highest_number_pairings::{ NumberPairingProblem, Partition, PartitionProblem };
+
+#[test]
+fn two_part_solve_matches_the_pairing_problem() {
+    for sum in [1.0, 8.0, 100.0] {
+        let partition = PartitionProblem::new(sum, 2).solve();
+        let problem = NumberPairingProblem::builder().solve(sum);
+        let results = problem.results().expect("a solved problem has results");
+        let (low, high) = results.best_pairing()[0].as_sorted();
+        let mut parts = partition.parts().to_vec();
+        parts.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert!((partition.result() - results.best()).abs() <= results.best() * 1e-9, "{} vs {}", partition, results.best());
+        assert!((parts[0] - low).abs() < sum * 0.001 && (parts[1] - high).abs() < sum * 0.001, "{}", partition);
+    }
+}
+
+#[test]
+fn the_parts_add_up_to_the_sum() {
+    for parts in 1..=5 {
+        let partition = PartitionProblem::new(6.0, parts).solve();
+        assert_eq!(partition.parts().len(), parts);
+        assert!((partition.sum() - 6.0).abs() < 1e-9, "{}", partition);
+        assert!(partition.parts().iter().all(|part| *part >= 0.0), "{}", partition);
+    }
+}
+
+#[test]
+fn pruned_two_part_solve_matches_the_pairing_problem() {
+    let partition = PartitionProblem::new(8.0, 2).solve_pruned(8000);
+    let results = NumberPairingProblem::builder().solve(8.0);
+    let results = results.results().expect("a solved problem has results");
+    let best_pairing = results.best_pairing()[0];
+    let (low, high) = best_pairing.as_sorted();
+    assert!((partition.result() - results.best()).abs() < 0.000_01, "{} vs {}", partition, results.best());
+    assert!((partition.parts()[0] - low).abs() < 0.001 && (partition.parts()[1] - high).abs() < 0.001, "{}", partition);
+}
+
+#[test]
+fn pruned_three_part_solve_matches_brute_force() {
+    let (sum, steps) = (6.0, 60);
+    let step = sum / steps as f64;
+    let mut brute_force_best = f64::NEG_INFINITY;
+    for first in 0..=steps {
+        for second in 0..=(steps - first) {
+            let parts = vec![first as f64 * step, second as f64 * step, (steps - first - second) as f64 * step];
+            brute_force_best = brute_force_best.max(Partition::new(parts).result());
+        }
+    }
+    let partition = PartitionProblem::new(sum, 3).solve_pruned(steps);
+    assert!((partition.result() - brute_force_best).abs() < 0.000_000_001, "{} vs {}", partition, brute_force_best);
+}
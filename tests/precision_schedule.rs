@@ -0,0 +1,13 @@
+use highest_number_pairings::NumberPairingProblem;
+
+#[test]
+fn adaptive_precision_solves_in_fewer_runs() {
+    for sum in [1.0, 8.0, 1000.0, 1_000_000.0] {
+        let fixed = NumberPairingProblem::builder().seeded(false).solve(sum);
+        let adaptive = NumberPairingProblem::builder().seeded(false).adaptive_precision(true).solve(sum);
+        assert!(adaptive.runs_to_solve() < fixed.runs_to_solve(), "sum {}: {} vs {} runs", sum, adaptive.runs_to_solve(), fixed.runs_to_solve());
+        let optimum = NumberPairingProblem::solve_analytic(sum).result();
+        let best = adaptive.results().expect("a solved problem has results").best();
+        assert!((best - optimum).abs() <= optimum * 0.000_000_001, "sum {}: best {} vs optimum {}", sum, best, optimum);
+    }
+}
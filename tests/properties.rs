@@ -0,0 +1,47 @@
+use highest_number_pairings::NumberPairingProblem;
+use proptest::prelude::*;
+
+/// Values are compared relative to the analytic optimum, which grows with the cube of the sum
+const RELATIVE_TOLERANCE: f64 = 0.000_000_001;
+
+/// Sums across (0, 1e6), shrinking towards small sums
+fn sums() -> impl Strategy<Value = f64> {
+    0.000_001..1_000_000.0_f64
+}
+
+proptest! {
+    #[test]
+    fn best_matches_the_analytic_optimum(sum in sums()) {
+        let problem = NumberPairingProblem::builder().solve(sum);
+        let results = problem.results().expect("a solved problem has results");
+        let optimum = NumberPairingProblem::solve_analytic(sum).result();
+        prop_assert!((results.best() - optimum).abs() <= optimum * RELATIVE_TOLERANCE, "best {} vs optimum {}", results.best(), optimum);
+    }
+
+    #[test]
+    fn best_pairings_all_produce_the_best_value(sum in sums()) {
+        let problem = NumberPairingProblem::builder().solve(sum);
+        let results = problem.results().expect("a solved problem has results");
+        prop_assert!(!results.best_pairing().is_empty());
+        for pairing in results.best_pairing() {
+            prop_assert!((pairing.result() - results.best()).abs() <= results.best() * RELATIVE_TOLERANCE);
+        }
+    }
+
+    #[test]
+    fn other_results_are_all_worse_than_the_best(sum in sums()) {
+        let problem = NumberPairingProblem::builder().seeded(false).solve(sum);
+        let results = problem.results().expect("a solved problem has results");
+        for pairing in results.other_results().unwrap_or_default() {
+            prop_assert!(pairing.result() < results.best(), "{:?} is not worse than {}", pairing, results.best());
+        }
+    }
+
+    #[test]
+    fn unseeded_search_converges_on_the_optimum(sum in sums()) {
+        let problem = NumberPairingProblem::builder().seeded(false).solve(sum);
+        let results = problem.results().expect("a solved problem has results");
+        let optimum = NumberPairingProblem::solve_analytic(sum).result();
+        prop_assert!((results.best() - optimum).abs() <= optimum * RELATIVE_TOLERANCE, "best {} vs optimum {}", results.best(), optimum);
+    }
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "num-rational")]
+
+use highest_number_pairings::NumberPairing;
+use num_rational::Ratio;
+
+#[test]
+fn two_and_six_is_exactly_forty_eight() {
+    let pairing = NumberPairing::new(2.0, 8.0);
+    assert_eq!(pairing.result_rational(), Some(Ratio::from_integer(48)));
+    assert_eq!(NumberPairing::new(6.0, 8.0).result_rational(), Some(Ratio::from_integer(48)));
+}
+
+#[test]
+fn simple_fractions_are_exact() {
+    // 1/2 × 3/2 × 1 = 3/4
+    assert_eq!(NumberPairing::new(0.5, 2.0).result_rational(), Some(Ratio::new(3, 4)));
+}
+
+#[test]
+fn overflowing_results_are_none() {
+    assert_eq!(NumberPairing::new(1e12, 4e12).result_rational(), None);
+}
@@ -0,0 +1,44 @@
+use highest_number_pairings::NumberPairingProblem;
+
+fn firsts(problem: &NumberPairingProblem) -> (Vec<f64>, Vec<f64>) {
+    let results = problem.results().expect("the problem is solved");
+    let best = results.best_pairing().iter().map(|pairing| pairing.first()).collect();
+    let others = results.other_results().unwrap_or(&[]).iter().map(|pairing| pairing.first()).collect();
+    (best, others)
+}
+
+/// Output recorded from the recursion (once the last run's results were kept as other results)
+#[test]
+fn seeded_output_is_unchanged() {
+    let recorded = [
+        (1.0, 0.21132486540518708, [0.2738248654051871, 0.14882486540518708, 0.3363248654051871, 0.08632486540518708, 0.4613248654051871], 0.0962250448649376),
+        (8.0, 1.6905989232414966, [2.1905989232414966, 1.1905989232414966, 2.6905989232414966, 0.6905989232414966, 3.6905989232414966], 49.26722297084805),
+        (10.0, 2.113248654051871, [2.738248654051871, 1.488248654051871, 3.363248654051871, 0.8632486540518709, 4.613248654051871], 96.22504486493763),
+        (1000.0, 211.3248654051871, [273.8248654051871, 148.8248654051871, 336.3248654051871, 86.3248654051871, 461.3248654051871], 96225044.86493763),
+    ];
+    for (sum, best_first, other_firsts, best) in recorded {
+        let problem = NumberPairingProblem::builder().solve(sum);
+        assert_eq!(problem.runs_to_solve(), 2);
+        assert_eq!(problem.results().unwrap().best(), best);
+        assert_eq!(firsts(&problem), (vec![best_first], other_firsts.to_vec()));
+    }
+}
+
+/// Other results recorded from the recursion before it reused scratch buffers between runs
+#[test]
+fn unseeded_other_results_are_unchanged() {
+    let problem = NumberPairingProblem::builder().seeded(false).solve(8.0);
+    let (_, others) = firsts(&problem);
+    assert_eq!(others, [1.75, 1.625, 1.5625, 1.5, 1.4375, 2.0, 1.375, 1.3125, 1.25, 1.0, 2.5, 3.0, 4.0]);
+}
+
+#[test]
+fn solving_again_gives_identical_output() {
+    for sum in [1.0, 8.0, 10.0, 1000.0] {
+        let first = NumberPairingProblem::builder().solve(sum);
+        let second = NumberPairingProblem::builder().solve(sum);
+        assert_eq!(first.runs_to_solve(), second.runs_to_solve());
+        assert_eq!(first.results().unwrap().best(), second.results().unwrap().best());
+        assert_eq!(firsts(&first), firsts(&second));
+    }
+}
@@ -0,0 +1,29 @@
+use highest_number_pairings::{ NumberPairing, NumberPairingProblem };
+
+#[test]
+fn a_stalled_search_is_refined_to_the_optimum() {
+    // With no margin, the second run only revisits the first run's best number, so it can't improve on it,
+    // and the optimum is left between the first run's grid points
+    let problem = NumberPairingProblem::builder().margin_factor(0.0).solve(8.0);
+    assert_eq!(problem.runs_to_solve(), 2);
+    let optimum = NumberPairingProblem::solve_analytic(8.0);
+    let best_pairing = problem.results().expect("a solved problem has results").best_pairing()[0];
+    assert!((best_pairing.first() - optimum.first()).abs() < 0.000_001, "{:?}", best_pairing);
+    assert!(problem.verify(0.000_000_001));
+}
+
+#[test]
+fn refining_keeps_tied_best_pairings() {
+    // Two equal peaks, at 1.1 and 2.9, which the first run's grid (in steps of 0.5) misses by the same amount
+    let two_peaks = |pairing: &NumberPairing| -> f64 {
+        -(pairing.first() - 1.1).powi(2) * (pairing.first() - 2.9).powi(2)
+    };
+    let problem = NumberPairingProblem::builder()
+        .objective(two_peaks)
+        .initial_precision(0.5)
+        .margin_factor(0.0)
+        .solve(8.0);
+    let best_pairing = problem.results().expect("a solved problem has results").best_pairing();
+    assert_eq!(best_pairing.len(), 2, "{:?}", best_pairing);
+    assert!((best_pairing[0].first() - 1.1).abs() < 0.000_001 && (best_pairing[1].first() - 2.9).abs() < 0.000_001, "{:?}", best_pairing);
+}
@@ -0,0 +1,99 @@
+use highest_number_pairings::{ Goal, NumberPairing, NumberPairingProblem, MINIMUM_PRECISION };
+
+#[test]
+fn the_default_results_can_be_read_back() {
+    let problem = NumberPairingProblem::solve_default();
+    let results = problem.results().expect("the default problem is solved");
+    assert!((results.best() - 49.2672).abs() < 0.0001, "{}", results.best());
+    assert!(!results.best_pairing().is_empty());
+    // The best pairings are tied within the minimum precision scaled to the sum
+    assert!(results.best_pairing().iter().all(|pairing| (pairing.result() - results.best()).abs() <= results.best() * 1e-9));
+    let other = results.other_results().expect("the default problem collects other results");
+    assert!(other.iter().all(|pairing| pairing.result() < results.best()));
+}
+
+#[test]
+fn an_unsolved_problem_has_no_results() {
+    let problem = NumberPairingProblem::solve_constrained(8.0, 9.0, 10.0, true);
+    assert!(problem.results().is_none());
+}
+
+#[test]
+fn the_default_problem_is_solved_in_some_runs() {
+    let problem = NumberPairingProblem::solve_default();
+    assert!(problem.is_solved());
+    assert!(problem.runs_to_solve() > 0);
+    assert_eq!(problem.sum, 8.0);
+}
+
+#[test]
+fn a_problem_without_results_isnt_solved() {
+    let problem = NumberPairingProblem::solve_constrained(8.0, 9.0, 10.0, true);
+    assert!(!problem.is_solved());
+    assert_eq!(problem.runs_to_solve(), 0);
+}
+
+#[test]
+fn the_default_result_is_the_optimum_rather_than_the_initial_pairing() {
+    let problem = NumberPairingProblem::solve_default();
+    let results = problem.results().unwrap();
+    let optimum = NumberPairingProblem::solve_analytic(8.0);
+    assert!((results.best() - optimum.result()).abs() < 0.000_000_01, "{}", results.best());
+    assert!(results.best_pairing().iter().all(|pairing| (pairing.as_sorted().0 - optimum.first()).abs() < 0.000_1));
+    assert!(results.iter().all(|pairing| pairing.first() != 0.0 && pairing.second() != 0.0));
+}
+
+#[test]
+fn the_top_five_start_with_the_optimum_and_never_increase() {
+    let problem = NumberPairingProblem::solve_default();
+    let results = problem.results().unwrap();
+    let top = results.top_k(5);
+    assert_eq!(top.len(), 5);
+    assert_eq!(top[0].result(), results.best());
+    assert!(top.windows(2).all(|pair| pair[0].result() >= pair[1].result()));
+    assert!(top.iter().enumerate().all(|(index, pairing)| top[index + 1..].iter().all(|other| other != pairing)));
+}
+
+#[test]
+fn the_top_results_of_a_minimizing_solve_are_the_lowest() {
+    let distance_from_e = |pairing: &NumberPairing| (pairing.first() - std::f64::consts::E).abs();
+    let problem = NumberPairingProblem::builder().objective(distance_from_e).goal(Goal::Minimize).solve(8.0);
+    let results = problem.results().unwrap();
+    let top = results.top_k(3);
+    assert_eq!(top.len(), 3);
+    assert_eq!(distance_from_e(&top[0]), results.best());
+    assert!(top.windows(2).all(|pair| distance_from_e(&pair[0]) < distance_from_e(&pair[1])));
+}
+
+#[test]
+fn the_second_best_is_below_the_best() {
+    let problem = NumberPairingProblem::solve_default();
+    let results = problem.results().unwrap();
+    let second_best = results.second_best().expect("the default solve collects other results");
+    assert!(second_best < results.best() - MINIMUM_PRECISION);
+    let highest_other = results.other_results().unwrap().iter().map(|pairing| pairing.result()).fold(f64::NEG_INFINITY, f64::max);
+    assert_eq!(second_best, highest_other);
+}
+
+#[test]
+fn the_second_best_follows_the_goal_and_the_scale_of_the_sum() {
+    let distance_from_e = |pairing: &NumberPairing| (pairing.first() - std::f64::consts::E).abs();
+    let problem = NumberPairingProblem::builder().objective(distance_from_e).goal(Goal::Minimize).solve(8.0);
+    let results = problem.results().unwrap();
+    let second_best = results.second_best().unwrap();
+    assert!(second_best > results.best());
+    let lowest_other = results.other_results().unwrap().iter().map(distance_from_e).fold(f64::INFINITY, f64::min);
+    assert_eq!(second_best, lowest_other);
+    // For a huge sum, the runner-up is distinct by the minimum precision scaled to the sum (as the result is to its cube)
+    let sum: f64 = 1e9;
+    let huge = NumberPairingProblem::builder().solve(sum);
+    let results = huge.results().unwrap();
+    let second_best = results.second_best().expect("the huge solve collects other results");
+    assert!(results.best() - second_best > MINIMUM_PRECISION * sum.powi(3));
+}
+
+#[test]
+fn without_other_results_there_is_no_second_best() {
+    let problem = NumberPairingProblem::builder().collect_other_results(false).solve(8.0);
+    assert_eq!(problem.results().unwrap().second_best(), None);
+}
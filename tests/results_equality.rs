@@ -0,0 +1,19 @@
+use highest_number_pairings::NumberPairingProblem;
+
+#[test]
+fn golden_section_and_recursive_results_are_approximately_equal() {
+    for sum in [1.0, 8.0, 1000.0] {
+        let golden = NumberPairingProblem::solve_golden_section(sum, false);
+        let recursive = NumberPairingProblem::builder().collect_other_results(false).solve(sum);
+        let tolerance = sum * 0.000_1;
+        assert!(golden.results().unwrap().approx_eq(recursive.results().unwrap(), tolerance), "sum {}: {:?} vs {:?}", sum, golden.results(), recursive.results());
+    }
+}
+
+#[test]
+fn results_for_different_sums_are_not_equal() {
+    let eight = NumberPairingProblem::builder().solve(8.0);
+    let nine = NumberPairingProblem::builder().solve(9.0);
+    assert!(!eight.results().unwrap().approx_eq(nine.results().unwrap(), 0.001));
+    assert!(eight.results().unwrap().approx_eq(eight.results().unwrap(), 0.0));
+}
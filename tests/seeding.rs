@@ -0,0 +1,18 @@
+use highest_number_pairings::NumberPairingProblem;
+
+#[test]
+fn seeding_the_first_run_solves_in_fewer_runs() {
+    let unseeded = NumberPairingProblem::builder().seeded(false).solve(8.0);
+    let seeded = NumberPairingProblem::builder().solve(8.0);
+    assert!(seeded.runs_to_solve() < unseeded.runs_to_solve(), "{} vs {} runs", seeded.runs_to_solve(), unseeded.runs_to_solve());
+    assert!(seeded.verify(0.000_000_001) && unseeded.verify(0.000_000_001));
+}
+
+#[test]
+fn a_seeded_solve_still_collects_other_results() {
+    let problem = NumberPairingProblem::solve_default();
+    let results = problem.results().unwrap();
+    assert_eq!(problem.runs_to_solve(), 2);
+    assert_eq!(results.top_k(5).len(), 5);
+    assert!(results.other_results().unwrap().iter().all(|pairing| pairing.result() < results.best()));
+}
@@ -0,0 +1,38 @@
+use highest_number_pairings::{ NumberPairing, NumberPairingProblem, SolveError };
+
+#[test]
+fn non_finite_sums_are_an_error() {
+    for sum in [f64::INFINITY, f64::NEG_INFINITY, f64::NAN] {
+        assert_eq!(NumberPairingProblem::solve_checked(sum).err(), Some(SolveError::NonFiniteSum), "sum {}", sum);
+    }
+}
+
+#[test]
+fn non_positive_sums_are_an_error() {
+    for sum in [0.0, -0.0, -8.0] {
+        assert_eq!(NumberPairingProblem::solve_checked(sum).err(), Some(SolveError::NonPositiveSum), "sum {}", sum);
+    }
+}
+
+#[test]
+fn sums_with_an_overflowing_result_are_an_error() {
+    assert_eq!(NumberPairingProblem::solve_checked(1e200).err(), Some(SolveError::ResultOverflows));
+}
+
+#[test]
+fn valid_sums_are_solved_without_panicking() {
+    for sum in [f64::MIN_POSITIVE, 1e-9, 8.0, 1e12] {
+        let problem = NumberPairingProblem::solve_checked(sum).expect("the sum is valid");
+        assert!(problem.is_solved(), "sum {}", sum);
+        assert!(problem.results().unwrap().best().is_finite(), "sum {}", sum);
+    }
+}
+
+#[test]
+fn a_huge_sum_has_no_checked_result() {
+    let optimum = NumberPairing::optimal_for(1e200);
+    assert!(optimum.result().is_infinite());
+    assert_eq!(optimum.checked_result(), None);
+    assert_eq!(NumberPairing::new(2.0, 8.0).checked_result(), Some(48.0));
+    assert_eq!(NumberPairingProblem::solve_checked(1e200).err(), Some(SolveError::ResultOverflows));
+}
@@ -0,0 +1,28 @@
+use highest_number_pairings::{ NumberPairingProblem, MINIMUM_PRECISION };
+
+fn discrepancy_of(report: &str) -> f64 {
+    let line = report.lines().find(|line| line.starts_with("Discrepancy: ")).expect("the report has a discrepancy line");
+    line["Discrepancy: ".len()..].parse().expect("the discrepancy is a number")
+}
+
+#[test]
+fn a_seeded_solve_has_a_discrepancy_below_the_minimum_precision() {
+    let problem = NumberPairingProblem::builder().solve(8.0);
+    let report = problem.report().to_string();
+    assert!(discrepancy_of(&report) < MINIMUM_PRECISION, "{}", report);
+}
+
+#[test]
+fn a_default_solve_has_a_discrepancy_below_the_minimum_precision_of_the_optimum() {
+    let problem = NumberPairingProblem::solve_default();
+    let report = problem.report().to_string();
+    let analytic = NumberPairingProblem::solve_analytic(8.0).result();
+    assert!(discrepancy_of(&report) < MINIMUM_PRECISION * analytic, "{}", report);
+    assert!(report.contains(&format!("Solved in {} runs", problem.runs_to_solve())), "{}", report);
+}
+
+#[test]
+fn an_unsolved_problem_has_no_report() {
+    let problem = NumberPairingProblem::solve_constrained(8.0, 9.0, 10.0, true);
+    assert!(problem.report().to_string().contains("has not yet been solved"));
+}
@@ -0,0 +1,16 @@
+use highest_number_pairings::NumberPairing;
+
+#[test]
+fn either_order_sorts_smaller_first() {
+    assert_eq!(NumberPairing::new(2.0, 8.0).as_sorted(), (2.0, 6.0));
+    assert_eq!(NumberPairing::new(6.0, 8.0).as_sorted(), (2.0, 6.0));
+    assert_eq!(NumberPairing::new(4.0, 8.0).as_sorted(), (4.0, 4.0));
+}
+
+#[test]
+fn either_order_displays_the_same() {
+    let pairing = NumberPairing::new(2.0, 8.0);
+    let mirror = NumberPairing::new(6.0, 8.0);
+    assert_eq!(pairing.to_string(), mirror.to_string());
+    assert!(mirror.to_string().starts_with("2 and 6 -> 8"), "{}", mirror);
+}
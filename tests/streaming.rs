@@ -0,0 +1,22 @@
+use highest_number_pairings::{ NumberPairingProblem, SolveEvent };
+
+#[test]
+fn streaming_ends_with_the_same_results_as_solving() {
+    let (receiver, handle) = NumberPairingProblem::solve_streaming(8.0, true);
+    let events: Vec<SolveEvent> = receiver.iter().collect();
+    handle.join().expect("the solving thread doesn't panic");
+    assert!(events.len() > 1);
+    assert!(events[..events.len() - 1].iter().all(|event| matches!(event, SolveEvent::Run { .. })));
+    let expected = NumberPairingProblem::builder().solve(8.0);
+    match events.last() {
+        Some(SolveEvent::Done(results)) => assert_eq!(results.best(), expected.results().unwrap().best()),
+        other => panic!("expected the last event to be Done, but got {:?}", other),
+    }
+}
+
+#[test]
+fn dropping_the_receiver_stops_the_search() {
+    let (receiver, handle) = NumberPairingProblem::solve_streaming(8.0, true);
+    drop(receiver);
+    handle.join().expect("the solving thread finishes without panicking");
+}
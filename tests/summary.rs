@@ -0,0 +1,24 @@
+use highest_number_pairings::NumberPairingProblem;
+
+#[test]
+fn the_summary_has_the_sum_best_and_runs() {
+    let problem = NumberPairingProblem::solve_default();
+    let summary = problem.summary();
+    assert!(summary.starts_with("sum=8: "), "{}", summary);
+    assert!(summary.contains("best=49.2672"), "{}", summary);
+    assert!(summary.contains("at (1.6906, 6.3094)"), "{}", summary);
+    assert!(summary.ends_with(&format!("in {} runs", problem.runs_to_solve())), "{}", summary);
+    assert_eq!(summary.lines().count(), 1);
+}
+
+#[test]
+fn a_single_run_is_singular() {
+    let problem = NumberPairingProblem::builder().max_runs(1).solve(8.0);
+    assert!(problem.summary().ends_with("in 1 run"), "{}", problem.summary());
+}
+
+#[test]
+fn an_unsolved_summary_says_so() {
+    let problem = NumberPairingProblem::solve_constrained(8.0, 9.0, 10.0, true);
+    assert_eq!(problem.summary(), "sum=8: not yet solved");
+}
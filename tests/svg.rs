@@ -0,0 +1,23 @@
+use highest_number_pairings::NumberPairingProblem;
+
+/// The value of the given attribute of the first element with the tag
+fn attribute<'a>(svg: &'a str, tag: &str, name: &str) -> &'a str {
+    let element = &svg[svg.find(tag).unwrap_or_else(|| panic!("the SVG has a {}", tag))..];
+    let start = element.find(&format!("{}=\"", name)).expect("the attribute is set") + name.len() + 2;
+    &element[start..start + element[start..].find('"').unwrap()]
+}
+
+#[test]
+fn the_svg_has_the_curve_and_a_marker_at_the_peak() {
+    let (width, height) = (800, 400);
+    let svg = NumberPairingProblem::to_svg(8.0, width, height);
+    assert!(svg.starts_with("<svg "), "{}", svg);
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert!(attribute(&svg, "<polyline", "points").split(' ').count() >= 2);
+    let expected_x = NumberPairingProblem::solve_analytic(8.0).first() / 8.0 * width as f64;
+    let x: f64 = attribute(&svg, "<circle", "cx").parse().unwrap();
+    let y: f64 = attribute(&svg, "<circle", "cy").parse().unwrap();
+    assert!((x - expected_x).abs() < 1.0, "{} vs {}", x, expected_x);
+    // The y-axis is scaled to the peak, which is at the top
+    assert_eq!(y, 0.0);
+}
@@ -0,0 +1,18 @@
+use highest_number_pairings::{ NumberPairingProblem, format_problem_table };
+
+#[test]
+fn the_table_has_a_header_and_a_row_per_problem() {
+    let problems: Vec<NumberPairingProblem> = [8.0, 1000.0].iter().map(|sum| NumberPairingProblem::builder().solve(*sum)).collect();
+    let table = format_problem_table(&problems);
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines.len(), 4, "{}", table);
+    let header: Vec<&str> = lines[0].split('|').map(str::trim).collect();
+    assert_eq!(header, ["sum", "best", "first", "second", "runs"]);
+    assert!(lines[1].chars().all(|character| character == '-'));
+    for row in &lines[2..] {
+        assert_eq!(row.split('|').count(), 5, "{}", row);
+        assert_eq!(row.len(), lines[0].len());
+    }
+    let runs = problems[0].runs_to_solve().to_string();
+    assert_eq!(lines[2].split('|').map(str::trim).collect::<Vec<&str>>(), ["8", "49.2672", "1.6906", "6.3094", runs.as_str()]);
+}
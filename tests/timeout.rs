@@ -0,0 +1,22 @@
+use highest_number_pairings::NumberPairingProblem;
+use std::time::Duration;
+
+#[test]
+fn a_very_short_timeout_still_gives_a_valid_pairing() {
+    let problem = NumberPairingProblem::solve_with_timeout(8.0, true, Duration::from_nanos(1));
+    let results = problem.results().expect("the problem is solved");
+    assert!(problem.runs_to_solve() >= 1);
+    assert!(results.best().is_finite() && results.best() > 0.0);
+    for pairing in results.best_pairing() {
+        assert!((0.0..=8.0).contains(&pairing.first()));
+        assert_eq!(pairing.result(), results.best());
+    }
+}
+
+#[test]
+fn a_generous_timeout_matches_the_uncapped_solve() {
+    let timed = NumberPairingProblem::solve_with_timeout(8.0, true, Duration::from_secs(60));
+    let uncapped = NumberPairingProblem::builder().solve(8.0);
+    assert_eq!(timed.runs_to_solve(), uncapped.runs_to_solve());
+    assert_eq!(timed.results().unwrap().best(), uncapped.results().unwrap().best());
+}
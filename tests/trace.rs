@@ -0,0 +1,28 @@
+use highest_number_pairings::{ NumberPairing, NumberPairingProblem };
+
+#[test]
+fn the_trace_narrows_toward_the_optimum() {
+    let (problem, trace) = NumberPairingProblem::solve_traced(8.0);
+    assert!(trace.len() > 20);
+    let optimum = NumberPairingProblem::solve_analytic(8.0);
+    let (early, late) = (&trace[..10], &trace[trace.len() - 10..]);
+    let farthest = |pairings: &[NumberPairing]| pairings.iter().map(|pairing| (pairing.as_sorted().0 - optimum.first()).abs()).fold(0.0, f64::max);
+    assert!(farthest(late) < 0.001);
+    assert!(farthest(early) > farthest(late), "{} vs {}", farthest(early), farthest(late));
+    let best = problem.results().unwrap().best();
+    assert!(trace.iter().any(|pairing| pairing.result() == best));
+}
+
+#[test]
+fn the_best_so_far_improves_as_the_trace_goes_on() {
+    let (_, trace) = NumberPairingProblem::solve_traced(8.0);
+    let best_so_far: Vec<f64> = trace
+        .iter()
+        .scan(f64::NEG_INFINITY, |best, pairing| {
+            *best = best.max(pairing.result());
+            Some(*best)
+        })
+        .collect();
+    let quarter = best_so_far.len() / 4;
+    assert!(best_so_far[quarter] < best_so_far[best_so_far.len() - 1]);
+}
@@ -0,0 +1,29 @@
+use highest_number_pairings::{ NumberPairing, PairingError };
+
+#[test]
+fn valid_input_creates_a_pairing() {
+    let pairing = NumberPairing::try_new(2.0, 8.0).expect("the input is valid");
+    assert_eq!((pairing.first(), pairing.second()), (2.0, 6.0));
+}
+
+#[test]
+fn a_negative_sum_is_an_error() {
+    assert_eq!(NumberPairing::try_new(2.0, -8.0), Err(PairingError::NegativeSum));
+}
+
+#[test]
+fn a_number_over_the_sum_is_an_error() {
+    assert_eq!(NumberPairing::try_new(9.0, 8.0), Err(PairingError::NumberExceedsSum));
+}
+
+#[test]
+fn a_negative_number_is_an_error() {
+    assert_eq!(NumberPairing::try_new(-2.0, 8.0), Err(PairingError::NumberNegative));
+}
+
+#[test]
+fn non_finite_input_is_an_error() {
+    assert_eq!(NumberPairing::try_new(f64::NAN, 8.0), Err(PairingError::NonFinite));
+    assert_eq!(NumberPairing::try_new(1.0, f64::INFINITY), Err(PairingError::NonFinite));
+    assert_eq!(NumberPairing::try_new(f64::NEG_INFINITY, 8.0), Err(PairingError::NonFinite));
+}
@@ -0,0 +1,23 @@
+use highest_number_pairings::{ Goal, NumberPairing, NumberPairingProblem, PoweredProductTimesDifference };
+
+#[test]
+fn a_solved_problem_verifies_but_a_truncated_one_does_not() {
+    assert!(NumberPairingProblem::solve_default().verify(0.000_000_001));
+    let truncated = NumberPairingProblem::builder().seeded(false).max_runs(1).solve(8.0);
+    assert!(!truncated.verify(0.000_000_001));
+}
+
+#[test]
+fn verifying_uses_the_objective_of_the_solve() {
+    let powered = NumberPairingProblem::builder().objective(PoweredProductTimesDifference::new(2, 1)).solve(8.0);
+    let best = powered.results().unwrap().best();
+    assert!(powered.verify(best * 1e-9), "{}", best);
+}
+
+#[test]
+fn an_objective_without_a_known_optimum_never_verifies() {
+    let product = |pairing: &NumberPairing| pairing.product();
+    assert!(!NumberPairingProblem::builder().objective(product).solve(8.0).verify(f64::INFINITY));
+    assert!(!NumberPairingProblem::builder().goal(Goal::Minimize).solve(8.0).verify(f64::INFINITY));
+    assert!(!NumberPairingProblem::solve_constrained(8.0, 9.0, 10.0, true).verify(f64::INFINITY));
+}
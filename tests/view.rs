@@ -0,0 +1,41 @@
+use highest_number_pairings::{ NumberPairingProblem, SortOrder };
+
+fn best_line(view: &str) -> &str {
+    view.lines().find(|line| line.starts_with("Best Result:")).expect("the view has a best result line")
+}
+
+#[test]
+fn the_best_result_is_shown_to_the_view_precision() {
+    let problem = NumberPairingProblem::builder().solve(8.0);
+    let view = problem.view().to_string();
+    assert!(best_line(&view).starts_with("Best Result: 49.2672 ("), "{}", view);
+    let view = problem.view().precision(2).to_string();
+    assert!(best_line(&view).starts_with("Best Result: 49.27 ("), "{}", view);
+}
+
+#[test]
+fn results_display_on_their_own() {
+    let problem = NumberPairingProblem::solve_default();
+    let display = problem.results().unwrap().to_string();
+    assert!(display.contains("Best Result:"), "{}", display);
+    assert!(display.lines().any(|line| line.starts_with("1.6906 and 6.3094 -> 8 ")), "{}", display);
+    assert!(display.contains("Other Top Results:"), "{}", display);
+}
+
+/// The lines listed under "Other Top Results:"
+fn other_lines(view: &str) -> Vec<&str> {
+    view.lines().skip_while(|line| *line != "Other Top Results:").skip(1).take_while(|line| !line.is_empty()).collect()
+}
+
+#[test]
+fn ascending_order_shows_the_smallest_other_result_first() {
+    let problem = NumberPairingProblem::solve_default();
+    let other = problem.results().unwrap().other_results().unwrap();
+    let smallest = other.last().unwrap().format(4, false);
+    let ascending = problem.view().display_limit(other.len()).sort_order(SortOrder::Ascending).to_string();
+    let descending = problem.view().display_limit(other.len()).to_string();
+    assert_eq!(other_lines(&ascending).first(), Some(&smallest.as_str()), "{}", ascending);
+    let mut reversed = other_lines(&descending);
+    reversed.reverse();
+    assert_eq!(other_lines(&ascending), reversed);
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "wasm")]
+
+use highest_number_pairings::wasm::solve_to_json;
+use serde_json::Value;
+
+#[test]
+fn the_binding_returns_the_best_pairing_as_json() {
+    let json: Value = serde_json::from_str(&solve_to_json(8.0).expect("8 can be solved")).expect("the output is JSON");
+    for key in ["first", "second", "sum", "product", "difference", "result"] {
+        assert!(json[key].is_f64(), "{} is missing from {}", key, json);
+    }
+    assert_eq!(json["sum"], 8.0);
+    assert!((json["result"].as_f64().unwrap() - 49.2672).abs() < 0.0001, "{}", json);
+    assert!((json["first"].as_f64().unwrap() + json["second"].as_f64().unwrap() - 8.0).abs() < 1e-12);
+}
+
+#[test]
+fn sums_that_cant_be_solved_return_none() {
+    for sum in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+        assert_eq!(solve_to_json(sum), None, "sum {}", sum);
+    }
+}